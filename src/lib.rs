@@ -1,7 +1,20 @@
-use std::{borrow::Cow, convert::TryFrom, fmt::Display, u64};
+use std::{
+    borrow::Cow,
+    convert::TryFrom,
+    fmt::{Alignment, Display, Write},
+    io,
+    ops::{Add, RangeInclusive, Sub},
+    sync::Mutex,
+    time::SystemTime,
+};
 
-use chrono::{Date, DateTime, Duration, Local, Utc};
-use math::round::floor;
+/* `Date` is deprecated in later `chrono` releases, but it remains part of our API. */
+#[allow(deprecated)]
+use chrono::Date;
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime,
+    TimeZone, Timelike, Utc, Weekday,
+};
 
 /**
 Provides a context-aware `DateTime` object; a given `DateTime` is made aware in the context of the
@@ -21,6 +34,7 @@ pub struct Elapsed {
     */
     datetime_context: DateTime<Local>,
     datetime: DateTime<Local>,
+    #[allow(deprecated)]
     date: Date<Local>,
     /**
     Also known as the `diff`, difference in time between a given `DateTime` and the `DateTime` used
@@ -29,7 +43,8 @@ pub struct Elapsed {
     pub duration: Duration,
     /**
     If the date has already `passed`, or `elapsed`, it's no longer `due` so we can skip some
-    processing, and format a more meaningful message on display.
+    processing, and format a more meaningful message on display. When there's no difference in
+    time, it's neither `passed` nor `due`.
     */
     pub passed: bool,
     /**
@@ -41,19 +56,18 @@ pub struct Elapsed {
     might be times when we want the raw `u64`.
     */
     pub cache: Cache,
-    /*
-    TODO:
-    Customising display format can be done here.
-    */
-    // format: &'static str,
-    /*
-    TODO:
-    epoch could eventually be useful for running a timer that can be started via CLI.
-    A tick of the clock would then be a trigger to calculate if a minute had elapsed.
-    */
-    // epoch: u64,
+    /** Customises how `process` populates the `cache` and how we're displayed. */
+    options: Options,
 }
 
+/* `Elapsed` is shared between threads by live updating widgets, keep it `Send` and `Sync`. */
+const _: fn() = || {
+    fn _assert_send_sync<T: Send + Sync>() {}
+    _assert_send_sync::<Elapsed>();
+    _assert_send_sync::<DurationElapsed>();
+    _assert_send_sync::<EpochTimer>();
+};
+
 /** Alias of `Elapsed`. */
 pub type DueDateTime = Elapsed;
 /** Alias of `Elapsed`. */
@@ -67,6 +81,456 @@ variants changes.
 */
 type Cache = [Option<TimeFrameTuple>; 8];
 
+/** Errors we return rather than panicking. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElapsedError {
+    /** The `TimeFrame` has no slot, the `cache` is smaller than the number of variants. */
+    CacheIndexOutOfBounds(TimeFrame),
+    /** The `Local` offset for `now` couldn't be determined, e.g. a misconfigured timezone. */
+    UnknownLocalOffset,
+    /** The Unix timestamp is invalid or out of range for `chrono`. */
+    TimestampOutOfRange,
+}
+
+impl Display for ElapsedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ElapsedError::CacheIndexOutOfBounds(tf) => {
+                write!(f, "No slot in the `cache` for `TimeFrame::{:?}`", tf)
+            }
+            ElapsedError::UnknownLocalOffset => write!(f, "Unable to determine the `Local` offset"),
+            ElapsedError::TimestampOutOfRange => {
+                write!(f, "Invalid or out of range Unix timestamp")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ElapsedError {}
+
+/**
+Options used to customise processing and display. `Default` gives you the out of the box
+behaviour, use the `set_*` methods on `Elapsed` to modify them.
+*/
+#[derive(Debug, Clone)]
+pub struct Options {
+    /**
+    Minimum number of `TimeFrame`s to display, counting down from the largest. Missing time frames
+    are padded with zeros, e.g. `2hr` becomes `2hr 0min` when set to `2`.
+    */
+    pub pad_units: usize,
+    /** How whole `TimeFrame`s are counted by `process`, see `Rounding`. */
+    pub rounding: Rounding,
+    /**
+    How whole weeks are counted, by `process` and `weeks`, e.g. `Some(Rounding::Ceil)` for `week 3`
+    of a sprint 15 days in. When `None`, `process` uses `rounding` and `weeks` rounds down.
+    */
+    pub week_rounding: Option<Rounding>,
+    /** Boundaries `process` rolls up at, see `ProcessThresholds`. */
+    pub thresholds: ProcessThresholds,
+    /** How we indicate whether the `DateTime` is in the past or future, see `SignMode`. */
+    pub sign_mode: SignMode,
+    /** How `TimeFrame`s are abbreviated, see `AbbrevStyle`. */
+    pub abbrev_style: AbbrevStyle,
+    /**
+    Drop zero valued `TimeFrame`s displayed before the first non-zero one, keeping those after it,
+    e.g. `0y 2mo 0w 3d` becomes `2mo 0w 3d`.
+    */
+    pub trim_leading_zeros: bool,
+    /** Separator used to group thousands in large values, e.g. `Some(',')` for `86,400sec`. */
+    pub digit_grouping: Option<char>,
+    /** Displayed instead when there's no difference in time at all, `now` by default. */
+    pub zero_text: Cow<'static, str>,
+    /** Append remaining milliseconds when displaying seconds, e.g. `1sec 500ms`. */
+    pub show_millis: bool,
+    /**
+    Display seconds with this many fractional digits, e.g. `Some(6)` for `1.234567sec`. Takes
+    precedence over `show_millis`, at most 9 digits (nanoseconds) are shown.
+    */
+    pub subsec_digits: Option<usize>,
+    /**
+    When the largest `TimeFrame` is at least this one, display only it, in words, prefixed with
+    `over` if smaller time frames were dropped, e.g. `over 1 year ago` rather than `1y 5mo ago`.
+    */
+    pub over_mode: Option<TimeFrame>,
+    /**
+    Which `TimeFrame`s are displayed, and in what order, e.g. seconds first. Unpopulated time
+    frames are skipped. Largest first when `None`.
+    */
+    pub frame_order: Option<Vec<TimeFrame>>,
+    /** What the context represents, appended when displayed, e.g. `3d ago (relative to deploy)`. */
+    pub reference_label: Option<String>,
+    /** Framing words used with `SignMode::Words`, `in` and `ago` when `None`, see `Semantics`. */
+    pub semantics: Option<Semantics>,
+    /** Vague words `humanize` uses for counts, e.g. `a few days`, see `VagueConfig`. */
+    pub vague: Option<VagueConfig>,
+    /**
+    Progress toward the next count, from `0.0` to `1.0`, past which `humanize` says `almost`, e.g.
+    `Some(0.9)` displays 29 years 11 months as `almost 30 years` rather than `30 years`.
+    */
+    pub almost_threshold: Option<f64>,
+    /**
+    Whether `total_seconds_with_leaps` counts leap seconds, off by default like `chrono`, which
+    ignores them. For scientific use.
+    */
+    pub count_leap_seconds: bool,
+    /**
+    Display the signed difference of each calendar field rather than a breakdown of the absolute
+    difference in time, e.g. `1y -2mo` from March 2020 to January 2021. For interval arithmetic,
+    ignored by `DurationElapsed`, which has no calendar.
+    */
+    pub signed_components: bool,
+    /**
+    `TimeFrame`s `process` never populates, their values roll into the next smaller time frame,
+    e.g. excluding weeks turns `2w 3d` into `17d`.
+    */
+    pub excluded: Vec<TimeFrame>,
+    /**
+    Allow the `*_and` methods to be chained in any order, e.g. `seconds_and().years_and()`, rather
+    than panicking unless largest first. Values are recalculated largest first on each call.
+    */
+    pub any_order: bool,
+    /**
+    Prefixed to the displayed breakdown when it doesn't exactly represent the difference in time,
+    e.g. `Some("~".into())` for `~1hr ago` or `Some("about ".into())` for `in about 4hr`.
+    */
+    pub approximate_marker: Option<Cow<'static, str>>,
+    /** Subject `describe` leads with, `This event` by default. */
+    pub describe_prefix: Cow<'static, str>,
+    /** `smart` displays relative time up until this cutoff, 30 days by default. */
+    pub smart_cutoff: Duration,
+    /** `strftime` style format `smart` uses beyond the cutoff, e.g. `on Jan 5, 2024`. */
+    pub smart_format: Cow<'static, str>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            pad_units: 0,
+            rounding: Rounding::default(),
+            week_rounding: None,
+            thresholds: ProcessThresholds::default(),
+            sign_mode: SignMode::default(),
+            abbrev_style: AbbrevStyle::default(),
+            trim_leading_zeros: false,
+            digit_grouping: None,
+            zero_text: "now".into(),
+            show_millis: false,
+            subsec_digits: None,
+            over_mode: None,
+            frame_order: None,
+            reference_label: None,
+            semantics: None,
+            vague: None,
+            almost_threshold: None,
+            count_leap_seconds: false,
+            signed_components: false,
+            excluded: Vec::new(),
+            any_order: false,
+            approximate_marker: None,
+            describe_prefix: "This event".into(),
+            smart_cutoff: Duration::days(30),
+            smart_format: "on %b %-d, %Y".into(),
+        }
+    }
+}
+
+impl Options {
+    /** Helper fn to get an elapsed style tuple. */
+    fn as_tuple(&self, tf: TimeFrame, val: u64) -> TimeFrameTuple {
+        (
+            format!("{}{}", self.digits(val), self.abbrev(tf)).into(),
+            val,
+        )
+    }
+
+    /**
+    Helper fn to get an elapsed style tuple for seconds with a fractional part, e.g. `1.5sec`. The
+    `u64` is still whole seconds.
+    */
+    fn as_subsec_tuple(&self, val: u64, nanos: u32, precision: usize) -> TimeFrameTuple {
+        let fraction = format!("{:09}", nanos);
+        let fraction = &fraction[..precision.min(fraction.len())];
+        let separator = if fraction.is_empty() { "" } else { "." };
+        let abbrev = self.abbrev(TimeFrame::Second);
+        (
+            format!("{}{}{}{}", self.digits(val), separator, fraction, abbrev).into(),
+            val,
+        )
+    }
+
+    /** Helper fn to get the digits of a value, grouped if `digit_grouping` is set. */
+    fn digits(&self, val: u64) -> String {
+        match self.digit_grouping {
+            Some(separator) => Self::group_digits(val, separator),
+            None => val.to_string(),
+        }
+    }
+
+    /** Helper fn to frame the `body` as past or future, e.g. `in 2d` or `2d ago`. */
+    fn frame(&self, direction: Direction, body: &str) -> String {
+        match self.affixes(direction) {
+            Some((prefix, suffix)) => format!("{}{}{}", prefix, body, suffix),
+            /* Neither passed, nor due. */
+            None => self.zero_text.to_string(),
+        }
+    }
+
+    /**
+    Helper fn to get the prefix and suffix framing a body as past or future, `None` when there's no
+    difference in time.
+    */
+    fn affixes(&self, direction: Direction) -> Option<(&'static str, &'static str)> {
+        match (self.sign_mode, direction) {
+            (_, Direction::Now) => None,
+            (SignMode::Words, direction) => Some(match (self.semantics, direction) {
+                (Some(Semantics::DueIn), Direction::Past) => ("overdue by ", ""),
+                (Some(Semantics::DueIn), _) => ("due in ", ""),
+                (Some(Semantics::ElapsedSince), Direction::Past) => ("", " since"),
+                (Some(Semantics::ElapsedSince), _) => ("", " until"),
+                (Some(Semantics::Between), _) => ("", " between"),
+                (None, Direction::Past) => ("", " ago"),
+                (None, _) => ("in ", ""),
+            }),
+            (SignMode::Symbol, Direction::Past) => Some(("-", "")),
+            (SignMode::Symbol, Direction::Future) => Some(("+", "")),
+            (SignMode::Arrow, Direction::Past) => Some(("↓", "")),
+            (SignMode::Arrow, Direction::Future) => Some(("↑", "")),
+        }
+    }
+
+    /** Helper fn to get the populated strings in the `cache` in display order. */
+    fn parts<'a>(&self, cache: &'a Cache) -> Vec<&'a str> {
+        self.displayed(cache)
+            .into_iter()
+            .map(|(_, tuple)| tuple.0.as_ref())
+            .collect()
+    }
+
+    /** Helper fn to get the populated `TimeFrame`s in the `cache` in display order. */
+    fn displayed<'a>(&self, cache: &'a Cache) -> Vec<(TimeFrame, &'a TimeFrameTuple)> {
+        let tuples: Vec<(TimeFrame, &TimeFrameTuple)> = match &self.frame_order {
+            Some(order) => order
+                .iter()
+                .filter_map(|tf| Some((*tf, cache[*tf as usize].as_ref()?)))
+                .collect(),
+            None => (0..cache.len())
+                .rev()
+                .filter_map(|i| Some((TimeFrame::from_index(i), cache[i].as_ref()?)))
+                .collect(),
+        };
+        tuples
+            .into_iter()
+            .skip_while(|(_, tuple)| self.trim_leading_zeros && tuple.1 == 0)
+            .collect()
+    }
+
+    /**
+    Helper fn to check whether the first `count` displayed `TimeFrame`s exactly represent
+    `duration`. Months and years vary in length, so are never exact.
+    */
+    fn is_exact(&self, cache: &Cache, count: usize, duration: Duration) -> bool {
+        let mut millis = 0;
+        for (tf, tuple) in self.displayed(cache).into_iter().take(count) {
+            if tf >= TimeFrame::Month {
+                return false;
+            }
+            millis += tuple.1 * tf.millis();
+            if tf == TimeFrame::Second && self.subsec_digits.is_some_and(|p| p >= 3) {
+                millis += u64::from(subsec_nanos(duration)) / 1_000_000;
+            }
+        }
+        millis == duration.num_milliseconds().unsigned_abs()
+    }
+
+    /** Helper fn to get the `over 1 year` style body when `over_mode` applies to the `cache`. */
+    fn over(&self, cache: &Cache) -> Option<String> {
+        let threshold = self.over_mode?;
+        let largest = cache.iter().rposition(Option::is_some)?;
+        let tf = TimeFrame::from_index(largest);
+        if tf < threshold {
+            return None;
+        }
+        let val = cache[largest].as_ref()?.1;
+        let dropped = cache[..largest].iter().flatten().any(|(_, val)| *val > 0);
+        let body = pluralize(tf, val);
+        Some(if dropped {
+            format!("over {}", body)
+        } else {
+            body
+        })
+    }
+
+    /** Helper fn to get the abbreviation we display a `TimeFrame` with. */
+    fn abbrev(&self, tf: TimeFrame) -> &'static str {
+        match self.abbrev_style {
+            AbbrevStyle::Standard => tf.abbrev(),
+            AbbrevStyle::Short => tf.abbrev_short(),
+            AbbrevStyle::Glyph => tf.glyph(),
+        }
+    }
+
+    /** Helper fn to separate thousands, e.g. `86400` becomes `86,400`. */
+    fn group_digits(val: u64, separator: char) -> String {
+        let digits = val.to_string();
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+}
+
+/** How the direction of the difference in time is displayed. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SignMode {
+    /** `in 2d` or `2d ago`. */
+    #[default]
+    Words,
+    /** `+2d` or `-2d`. */
+    Symbol,
+    /** `↑2d` or `↓2d`. */
+    Arrow,
+}
+
+/**
+What the difference in time means, tuning the framing words when `SignMode::Words` is used. This
+gives the `DueDateTime` and `TimeBetween` aliases distinct meaning, see `new_with_semantics`.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Semantics {
+    /** `due in 2d` or `overdue by 2d`. */
+    DueIn,
+    /** `2d since` or `2d until`. */
+    ElapsedSince,
+    /** `2d between`, direction isn't relevant. */
+    Between,
+}
+
+/**
+Boundaries the `process` heuristic rolls up at, inspect them with `Elapsed::thresholds` or override
+them with `Elapsed::set_thresholds`. `Default` gives the boundaries tabled in `Breakdown::process`.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessThresholds {
+    /**
+    Weeks that roll up to a month, e.g. `4` for `1mo` at 28 days. Only used without a calendar to
+    count months on, i.e. for a `DurationElapsed`.
+    */
+    pub weeks_per_month: u64,
+    /** Months that roll up to a year, e.g. `12` for `1y`. */
+    pub months_per_year: u64,
+    /** Hours from which minutes are dropped, e.g. `4` for `4hr` rather than `3hr 59min`. */
+    pub hours_alone: u64,
+    /** Minutes from which seconds are dropped, e.g. `5` for `5min` rather than `4min 59sec`. */
+    pub minutes_alone: u64,
+}
+
+impl Default for ProcessThresholds {
+    fn default() -> Self {
+        Self {
+            weeks_per_month: 4,
+            months_per_year: 12,
+            hours_alone: 4,
+            minutes_alone: 5,
+        }
+    }
+}
+
+/** How whole `TimeFrame`s are counted when processing, e.g. whether 23 hours is `23hr` or `1d`. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /** Round down, only whole `TimeFrame`s count. */
+    #[default]
+    Floor,
+    /** Promote to the next `TimeFrame` when the remainder is at least half, e.g. 12hr is `1d`. */
+    RoundNearest,
+    /** Round up, any remainder counts as a whole `TimeFrame`, e.g. 1hr is `1d`. */
+    Ceil,
+}
+
+impl Rounding {
+    /** Helper fn to divide `val` into whole `unit`s according to the rounding mode. */
+    fn div(self, val: u64, unit: u64) -> u64 {
+        match self {
+            Rounding::Floor => val / unit,
+            Rounding::RoundNearest => val / unit + u64::from(val % unit >= unit - unit / 2),
+            Rounding::Ceil => val / unit + u64::from(val % unit != 0),
+        }
+    }
+}
+
+/**
+Ranges of counts `humanize` phrases vaguely, e.g. `a few days` rather than `3 days`. The first range
+a count falls in is used, counts outside every range are left as they are.
+*/
+#[derive(Debug, Clone)]
+pub struct VagueConfig {
+    pub ranges: Vec<(RangeInclusive<u64>, Cow<'static, str>)>,
+}
+
+impl Default for VagueConfig {
+    fn default() -> Self {
+        Self {
+            ranges: vec![
+                (2..=2, "a couple".into()),
+                (3..=4, "a few".into()),
+                (5..=9, "several".into()),
+                (10..=u64::MAX, "many".into()),
+            ],
+        }
+    }
+}
+
+impl VagueConfig {
+    /** Helper fn to get the vague word for a count, if any. */
+    fn word(&self, count: u64) -> Option<&str> {
+        self.ranges
+            .iter()
+            .find(|(range, _)| range.contains(&count))
+            .map(|(_, word)| word.as_ref())
+    }
+}
+
+/** How `TimeFrame`s are abbreviated when displayed. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AbbrevStyle {
+    /** `3y 2mo 5sec`, see `Abbreviate::abbrev`. */
+    #[default]
+    Standard,
+    /** `3y 2mo 5s`, see `Abbreviate::abbrev_short`. */
+    Short,
+    /** `3ʸ 2ᵐᵒ 5ˢ`, see `TimeFrame::glyph`. */
+    Glyph,
+}
+
+/** Direction of the difference in time, unlike `passed` there's a distinct `Now`. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /** `DateTime` is before the `DateTime` given for context, e.g. `2d ago`. */
+    Past,
+    /** No difference in time at all. */
+    Now,
+    /** `DateTime` is after the `DateTime` given for context, e.g. `in 2d`. */
+    Future,
+}
+
+impl From<Duration> for Direction {
+    /** Direction _from_ the sign of a difference in time. */
+    fn from(duration: Duration) -> Self {
+        if duration < Duration::zero() {
+            Direction::Past
+        } else if duration > Duration::zero() {
+            Direction::Future
+        } else {
+            Direction::Now
+        }
+    }
+}
+
 impl Elapsed {
     /** Construct a new object then immediately process it. */
     pub fn new(datetime: DateTime<Local>) -> Self {
@@ -75,7 +539,24 @@ impl Elapsed {
         obj
     }
 
+    /** Construct a new object with no difference in time, at `now`, then immediately process it. */
+    pub fn now() -> Self {
+        let now = Local::now();
+        Self::new_with_context(now, now)
+    }
+
+    /**
+    Construct a new object then immediately process it, like `new`, but fails rather than silently
+    proceeding when the `Local` offset for `now` can't be determined.
+    */
+    pub fn try_new(datetime: DateTime<Local>) -> Result<Self, ElapsedError> {
+        let mut obj = Self::custom_with_context(datetime, try_now()?);
+        obj.process();
+        Ok(obj)
+    }
+
     /** Construct a new object from a `Date` rather than `DateTime` then immediately process it. */
+    #[allow(deprecated)]
     pub fn new_from_date(date: Date<Local>) -> Self {
         let mut obj = Self::custom_from_date(date);
         obj.process();
@@ -92,6 +573,7 @@ impl Elapsed {
     /**
     Construct a new object from a `Date` then add `Local` timezone then immediately process it.
     */
+    #[allow(deprecated)]
     pub fn new_from_date_then_localize(date: Date<Utc>) -> Self {
         let mut obj = Self::custom_from_date_then_localize(date);
         obj.process();
@@ -108,19 +590,154 @@ impl Elapsed {
         obj
     }
 
+    /**
+    Construct a new object with a custom `context`, displayed per `semantics` rather than with `in`
+    or `ago`, then immediately process it. E.g. `due in 3d` or `3d between`.
+    */
+    pub fn new_with_semantics(
+        datetime: DateTime<Local>,
+        context: DateTime<Local>,
+        semantics: Semantics,
+    ) -> Self {
+        let mut obj = Self::custom_with_context(datetime, context);
+        obj.options.semantics = Some(semantics);
+        obj.process();
+        obj
+    }
+
     /**
     Construct a new object from a `Date` with a custom `context`  then immediately process it.
     */
+    #[allow(deprecated)]
     pub fn new_from_date_with_context(date: Date<Local>, context: Date<Local>) -> Self {
         let mut obj = Self::custom_from_date_with_context(date, context);
         obj.process();
         obj
     }
 
+    /**
+    Construct a new object from two dates without times, both at local midnight, then immediately
+    process it. A midnight skipped by DST is moved forward to the first hour that exists.
+    */
+    pub fn from_dates(target: NaiveDate, context: NaiveDate) -> Self {
+        Self::new_with_context(
+            Self::localize_or_later(Self::midnight(target)),
+            Self::localize_or_later(Self::midnight(context)),
+        )
+    }
+
+    /**
+    Construct a new object from a `Duration`, relative to `now`, then immediately process it.
+    */
+    pub fn new_from_duration(duration: Duration) -> Self {
+        let mut obj = Self::custom_from_duration(duration);
+        obj.process();
+        obj
+    }
+
+    /**
+    Construct a new object from the gap between two differences in time, `a` less `b`, relative to
+    `now`, then immediately process it. E.g. `in 2d` when `a` is due in 3 days and `b` in 1 day.
+    Displayed with the `Options` of `a`.
+    */
+    pub fn diff_of(a: &Elapsed, b: &Elapsed) -> Self {
+        let mut obj = Self::custom_from_duration(a.duration - b.duration);
+        obj.options = a.options.clone();
+        obj.process();
+        obj
+    }
+
+    /**
+    Construct a sentinel object as far in the future as `chrono` can represent, so it's farther from
+    `now` than any other, e.g. to initialise a search for the nearest. Then immediately process it.
+    */
+    #[allow(deprecated)]
+    pub fn far_future() -> Self {
+        /* A day's grace so the offset to `Local` can't overflow. */
+        Self::new_then_localize(chrono::MAX_DATE.pred().and_hms(0, 0, 0))
+    }
+
+    /** Construct a sentinel object as far in the past as `chrono` allows, see `far_future`. */
+    #[allow(deprecated)]
+    pub fn far_past() -> Self {
+        Self::new_then_localize(chrono::MIN_DATE.succ().and_hms(0, 0, 0))
+    }
+
+    /**
+    Construct a new object from a `SystemTime` then immediately process it. Times before the
+    `UNIX_EPOCH` are supported, `chrono` handles the negative offset for us.
+    */
+    pub fn from_system_time(t: SystemTime) -> Self {
+        Self::new(DateTime::<Local>::from(t))
+    }
+
+    /**
+    Count down to `target`, yielding a freshly processed object straight away, then again every
+    `step`, for re-rendering on each tick. Ends once `target` has been reached. Requires the
+    `stream` feature, and a `tokio` runtime with the timer enabled.
+    */
+    #[cfg(feature = "stream")]
+    pub fn countdown_stream(
+        target: DateTime<Local>,
+        step: Duration,
+    ) -> impl futures_core::Stream<Item = Self> {
+        /* A negative `step` makes no sense, tick as fast as we can. */
+        let step = step.to_std().unwrap_or_default();
+        futures_util::stream::unfold((true, false), move |(first, finished)| async move {
+            if finished {
+                return None;
+            }
+            if !first {
+                tokio::time::sleep(step).await;
+            }
+            let elapsed = Self::new(target);
+            let finished = elapsed.duration <= Duration::zero();
+            Some((elapsed, (false, finished)))
+        })
+    }
+
+    /**
+    Construct a new object from a `time` crate `OffsetDateTime`, localize it, then immediately
+    process it. Converted via its Unix timestamp, so the offset is irrelevant. Requires the `time`
+    feature.
+    */
+    #[cfg(feature = "time")]
+    pub fn from_time_offset(datetime: time::OffsetDateTime) -> Self {
+        /* `time` only supports years -9999 to 9999, well within `chrono`'s range. */
+        let datetime = Utc
+            .timestamp_opt(datetime.unix_timestamp(), datetime.nanosecond())
+            .single()
+            .expect("`time` range fits in `chrono`");
+        Self::new_then_localize(datetime)
+    }
+
+    /**
+    Construct a new object from a Unix timestamp in seconds, localize it, then immediately process
+    it. Fails if the timestamp is out of range for `chrono`.
+    */
+    pub fn from_unix(secs: i64) -> Result<Self, ElapsedError> {
+        match Utc.timestamp_opt(secs, 0).single() {
+            Some(datetime) => Ok(Self::new_then_localize(datetime)),
+            None => Err(ElapsedError::TimestampOutOfRange),
+        }
+    }
+
+    /**
+    Construct a new object from a Unix timestamp in milliseconds, localize it, then immediately
+    process it. Fails if the timestamp is out of range for `chrono`.
+    */
+    pub fn from_unix_millis(millis: i64) -> Result<Self, ElapsedError> {
+        match Utc.timestamp_millis_opt(millis).single() {
+            Some(datetime) => Ok(Self::new_then_localize(datetime)),
+            None => Err(ElapsedError::TimestampOutOfRange),
+        }
+    }
+
     /**
     Construct a new object without processing. You must select the values to calculate via `years`
     or a sequence `years_and`, etc.
     */
+    #[allow(deprecated)]
     pub fn custom(datetime: DateTime<Local>) -> Self {
         let datetime_context = Local::now();
         Self {
@@ -128,8 +745,9 @@ impl Elapsed {
             datetime,
             date: datetime.date(),
             duration: datetime.signed_duration_since(datetime_context),
-            passed: datetime.le(&datetime_context),
+            passed: datetime.lt(&datetime_context),
             cache: Cache::default(),
+            options: Options::default(),
         }
     }
 
@@ -137,6 +755,7 @@ impl Elapsed {
     Construct a new object from a `Date` rather than `DateTime` without processing. You must select
     the values to calculate via `years` or a sequence `years_and`, etc.
     */
+    #[allow(deprecated)]
     pub fn custom_from_date(date: Date<Local>) -> Self {
         let datetime = date.and_hms(0, 0, 0);
         let datetime_context = Local::now();
@@ -145,8 +764,9 @@ impl Elapsed {
             datetime,
             date,
             duration: datetime.signed_duration_since(datetime_context),
-            passed: datetime.le(&datetime_context),
+            passed: datetime.lt(&datetime_context),
             cache: Cache::default(),
+            options: Options::default(),
         }
     }
 
@@ -154,6 +774,7 @@ impl Elapsed {
     Construct a new object and then add `Local` timezone without processing. You must select the
     values to calculate via `years` or a sequence `years_and`, etc.
     */
+    #[allow(deprecated)]
     pub fn custom_then_localize(datetime: DateTime<Utc>) -> Self {
         let datetime_context = Local::now();
         let datetime = datetime.with_timezone(&Local);
@@ -162,8 +783,9 @@ impl Elapsed {
             datetime,
             date: datetime.date(),
             duration: datetime.signed_duration_since(datetime_context),
-            passed: datetime.le(&datetime_context),
+            passed: datetime.lt(&datetime_context),
             cache: Cache::default(),
+            options: Options::default(),
         }
     }
 
@@ -171,6 +793,7 @@ impl Elapsed {
     Construct a new object from a `Date` then add `Local` timezone without processing. You must
     select the values to calculate via `years` or a sequence `years_and`, etc.
     */
+    #[allow(deprecated)]
     pub fn custom_from_date_then_localize(date: Date<Utc>) -> Self {
         let datetime = date.and_hms(0, 0, 0).with_timezone(&Local);
         let datetime_context = Local::now();
@@ -179,23 +802,35 @@ impl Elapsed {
             datetime,
             date: datetime.date(),
             duration: datetime.signed_duration_since(datetime_context),
-            passed: datetime.le(&datetime_context),
+            passed: datetime.lt(&datetime_context),
             cache: Cache::default(),
+            options: Options::default(),
         }
     }
 
+    /**
+    Construct a new object from a `Duration`, relative to `now`, without processing. You must select
+    the values to calculate via `years` or a sequence `years_and`, etc.
+    */
+    pub fn custom_from_duration(duration: Duration) -> Self {
+        let datetime_context = Local::now();
+        Self::custom_with_context(datetime_context + duration, datetime_context)
+    }
+
     /**
     Construct a new object with a custom `context`, rather than the default `now` without
     processing. You must select the values to calculate via `years` or a sequence `years_and`, etc.
     */
+    #[allow(deprecated)]
     pub fn custom_with_context(datetime: DateTime<Local>, context: DateTime<Local>) -> Self {
         Self {
             datetime_context: context,
             datetime,
             date: datetime.date(),
             duration: datetime.signed_duration_since(context),
-            passed: datetime.le(&context),
+            passed: datetime.lt(&context),
             cache: Cache::default(),
+            options: Options::default(),
         }
     }
 
@@ -203,6 +838,7 @@ impl Elapsed {
     Construct a new object from a `Date` with a custom `context` without processing. You must select
     the values to calculate via `years` or a sequence `years_and`, etc.
     */
+    #[allow(deprecated)]
     pub fn custom_from_date_with_context(date: Date<Local>, context: Date<Local>) -> Self {
         let datetime = date.and_hms(0, 0, 0);
         let datetime_context = context.and_hms(0, 0, 0);
@@ -211,8 +847,9 @@ impl Elapsed {
             datetime,
             date,
             duration: datetime.signed_duration_since(datetime_context),
-            passed: datetime.le(&datetime_context),
+            passed: datetime.lt(&datetime_context),
             cache: Cache::default(),
+            options: Options::default(),
         }
     }
 
@@ -220,472 +857,4290 @@ impl Elapsed {
     pub fn set_datetime_context(&mut self, datetime_context: DateTime<Local>) -> &mut Self {
         self.datetime_context = datetime_context;
         self.duration = self.datetime.signed_duration_since(datetime_context);
-        self.passed = self.datetime.le(&self.datetime_context);
+        self.passed = self.datetime.lt(&self.datetime_context);
         self.clear_cache();
         self.process();
         self
     }
 
+    /**
+    Move the `DateTime` given for context to the start of its calendar day, e.g. for "time since
+    the start of today". Will clear cached `diff` values.
+    */
+    pub fn anchor_context_to_day_start(&mut self) -> &mut Self {
+        let date = self.datetime_context.naive_local().date();
+        self.set_datetime_context(Self::localize_or_later(Self::midnight(date)))
+    }
+
+    /**
+    Move the `DateTime` given for context to the end of its calendar day, the last millisecond, e.g.
+    for "time until the end of today". Will clear cached `diff` values.
+    */
+    pub fn anchor_context_to_day_end(&mut self) -> &mut Self {
+        let date = self.datetime_context.naive_local().date();
+        let end = date
+            .and_hms_milli_opt(23, 59, 59, 999)
+            .expect("the last millisecond of the day is always valid");
+        let end = match Local.from_local_datetime(&end).latest() {
+            Some(end) => end,
+            /* Skipped by DST, the hour before will do. */
+            None => Self::localize_or_later(end - Duration::hours(1)),
+        };
+        self.set_datetime_context(end)
+    }
+
+    /** Helper fn to get the start of `date`, before it's localized. */
+    fn midnight(date: NaiveDate) -> NaiveDateTime {
+        date.and_hms_opt(0, 0, 0).expect("midnight is always valid")
+    }
+
+    /**
+    Helper fn to localize a `NaiveDateTime`. Times skipped by DST, e.g. a midnight that doesn't
+    exist, are moved forward to the first hour that does.
+    */
+    fn localize_or_later(mut naive: chrono::NaiveDateTime) -> DateTime<Local> {
+        loop {
+            if let Some(datetime) = Local.from_local_datetime(&naive).earliest() {
+                return datetime;
+            }
+            naive += Duration::hours(1);
+        }
+    }
+
     /** Set the `Elapsed`'s datetime. Will clear cached `diff` values. */
+    #[allow(deprecated)]
     pub fn set_datetime(&mut self, datetime: DateTime<Local>) -> &mut Self {
         self.datetime = datetime;
         self.date = datetime.date();
         self.duration = datetime.signed_duration_since(self.datetime_context);
-        self.passed = datetime.le(&self.datetime_context);
+        self.passed = datetime.lt(&self.datetime_context);
         self.clear_cache();
         self.process();
         self
     }
 
-    /** Set the `Elapsed`'s date. Will clear cached `diff` values. */
-    pub fn set_date(&mut self, date: Date<Local>) {
-        self.date = date;
-        self.datetime = date.and_hms(0, 0, 0);
-        self.duration = self.datetime.signed_duration_since(self.datetime_context);
-        self.passed = self.datetime.le(&self.datetime_context);
+    /**
+    Snap `DateTime` to the nearest whole `tf` from the `DateTime` given for context, e.g. due in
+    2hr 47min becomes due in 3hr, then reprocess. Months and years use their average length. Will
+    clear cached `diff` values.
+    */
+    pub fn quantize(&mut self, tf: TimeFrame) -> &mut Self {
+        let millis = self.duration.num_milliseconds();
+        let whole = Rounding::RoundNearest.div(millis.unsigned_abs(), tf.millis()) * tf.millis();
+        let whole = Duration::milliseconds(whole as i64);
+        let duration = if millis < 0 { -whole } else { whole };
+        self.set_datetime(self.datetime_context + duration)
+    }
+
+    /**
+    Set the `Elapsed`'s datetime_context, returning the previous one. Will clear cached `diff`
+    values.
+    */
+    pub fn replace_context(&mut self, datetime_context: DateTime<Local>) -> DateTime<Local> {
+        let previous = self.datetime_context;
+        self.set_datetime_context(datetime_context);
+        previous
+    }
+
+    /** Set the `Elapsed`'s datetime, returning the previous one. Will clear cached values. */
+    pub fn replace_datetime(&mut self, datetime: DateTime<Local>) -> DateTime<Local> {
+        let previous = self.datetime;
+        self.set_datetime(datetime);
+        previous
+    }
+
+    /**
+    Set the minimum number of `TimeFrame`s to display, see `Options::pad_units`. Will clear cached
+    `diff` values.
+    */
+    pub fn set_pad_units(&mut self, pad_units: usize) -> &mut Self {
+        self.options.pad_units = pad_units;
+        self.clear_cache();
+        self.process();
+        self
+    }
+
+    /** Set how `TimeFrame`s are abbreviated, see `AbbrevStyle`. Will clear cached `diff` values. */
+    pub fn set_abbrev_style(&mut self, abbrev_style: AbbrevStyle) -> &mut Self {
+        self.options.abbrev_style = abbrev_style;
+        self.clear_cache();
+        self.process();
+        self
+    }
+
+    /** Set how whole `TimeFrame`s are counted, see `Rounding`. Will clear cached `diff` values. */
+    pub fn set_rounding(&mut self, rounding: Rounding) -> &mut Self {
+        self.options.rounding = rounding;
         self.clear_cache();
         self.process();
+        self
+    }
+
+    /** Get the boundaries `process` rolls up at, see `ProcessThresholds`. */
+    pub fn thresholds(&self) -> ProcessThresholds {
+        self.options.thresholds
     }
 
     /**
-    Populate `cache` with contextually aware `TimeFrame`s. Discards "irrelevant" time frames, for
-    example if date is due in more than a year, we'll only store `1y 6m` as opposed to `1y 6m 2w 4d`.
+    Set the boundaries `process` rolls up at, see `ProcessThresholds`. Will clear cached `diff`
+    values.
     */
-    pub fn process(&mut self) {
-        /*
-        All absolute values, we can assume values are below zero later on when we check `passed`,
-        whilst we're building the str that represents time elapsed, we aren't concerned with past or
-        future.
+    pub fn set_thresholds(&mut self, thresholds: ProcessThresholds) -> &mut Self {
+        self.options.thresholds = thresholds;
+        self.clear_cache();
+        self.process();
+        self
+    }
 
-        `chrono` returns whole weeks, days, etc. so no rounding is present.
-        */
-        let diff = self.duration;
-        let weeks = diff.num_weeks().abs() as u64;
-        let days = diff.num_days().abs() as u64;
-        let hours = diff.num_hours().abs() as u64;
-        let minutes = diff.num_minutes().abs() as u64;
-        let seconds = diff.num_seconds().abs() as u64;
-        let _milliseconds = diff.num_milliseconds().abs() as u64;
-
-        if weeks > 0 {
-            if weeks > 0 && weeks < 4 {
-                /* In n weeks, simples. */
-                self.cache_insert(TimeFrame::Week, weeks);
-            } else
-            /* Months: */
-            {
-                /* Round down for months, easy for us to add remaining weeks. */
-                let months = floor((weeks / 4) as f64, 0) as u64;
-                /*
-                Get remaining weeks, e.g.:
-                6w [1m (+2w, rounded off)] - (1m * 4w) = 2w
-                */
-                let weeks_remaining = weeks - months * 4;
-                if months < 12
-                /* Less than a year: */
-                {
-                    self.cache_insert(TimeFrame::Month, months);
-                    self.cache_insert(TimeFrame::Week, weeks_remaining);
-                } else
-                /* Potentially multiple years */
-                {
-                    let years = floor((months / 12) as f64, 0) as u64;
-                    let months_remaining = months - years * 12;
-                    self.cache_insert(TimeFrame::Year, years);
-                    self.cache_insert(TimeFrame::Month, months_remaining);
-                }
-            }
-        } else if days > 0
-        /* and weeks are 0. */
-        {
-            self.cache_insert(TimeFrame::Day, days);
-        } else if hours >= 4
-        /* and days are 0. */
-        {
-            self.cache_insert(TimeFrame::Hour, hours);
-        } else if minutes >= 60
-        /* and in less than 4 hours. */
-        {
-            self.cache_insert(TimeFrame::Minute, minutes - hours * 60);
-        } else if minutes >= 5
-        /* and in less than an hour. */
-        {
-            self.cache_insert(TimeFrame::Minute, minutes);
-        } else if seconds > 0
-        /* and less 5 minutes away. */
-        {
-            /* Pads left with 0s: format!("{:0>1}:{:0>1}s", min, sec_remaining) */
-            self.cache_insert(TimeFrame::Minute, minutes);
-            self.cache_insert(TimeFrame::Second, seconds - minutes * 60);
-        }
+    /**
+    Set how whole weeks are counted, see `Options::week_rounding`. Will clear cached `diff`
+    values.
+    */
+    pub fn set_week_rounding(&mut self, week_rounding: Option<Rounding>) -> &mut Self {
+        self.options.week_rounding = week_rounding;
+        self.clear_cache();
+        self.process();
+        self
     }
 
-    /** Helper fn to insert a value for a `TimeFrame` into the cache. */
-    pub fn cache_insert(&mut self, k: TimeFrame, v: u64) {
-        self.cache[k as usize] = Some(Self::as_tuple(k, v));
+    /** Set whether leading zero valued `TimeFrame`s are dropped, see `trim_leading_zeros`. */
+    pub fn set_trim_leading_zeros(&mut self, trim_leading_zeros: bool) -> &mut Self {
+        self.options.trim_leading_zeros = trim_leading_zeros;
+        self
     }
 
-    /** Helper fn to keep the user in check before throwing wack values in the `cache`. */
-    fn protected_insert(&mut self, k: TimeFrame, v: u64) {
-        for i in 0..k as usize {
-            if let Some(_) = self.cache[i] {
-                panic!(
-                    "Please, let's try and be civil. Make your calls from largest `TimeFrame` to smallest."
-                )
-            }
-        }
-        self.cache_insert(k, v);
+    /** Set how the direction of the difference in time is displayed, see `SignMode`. */
+    pub fn set_sign_mode(&mut self, sign_mode: SignMode) -> &mut Self {
+        self.options.sign_mode = sign_mode;
+        self
     }
 
-    /** Helper fn to clear `HashMap`, bit unnecessary. */
-    pub fn clear_cache(&mut self) {
-        if !self.cache.is_empty() {
-            self.cache = Cache::default();
-        }
+    /** Set the framing words used with `SignMode::Words`, see `Semantics`. */
+    pub fn set_semantics(&mut self, semantics: Option<Semantics>) -> &mut Self {
+        self.options.semantics = semantics;
+        self
     }
 
-    /** Get number of years. */
-    pub fn num_years(&self) -> u64 {
-        floor((self.duration.num_weeks() / 52) as f64, 0) as u64
+    /** Set what's displayed when there's no difference in time, see `Options::zero_text`. */
+    pub fn set_zero_text(&mut self, zero_text: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.options.zero_text = zero_text.into();
+        self
     }
 
-    /** Get years between `DateTime` and `DateTime` given for context as `elapsed` style tuple. */
-    pub fn years(&mut self) -> TimeFrameTuple {
-        Self::as_tuple(TimeFrame::Year, self.num_years())
+    /**
+    Set whether remaining milliseconds are shown alongside seconds, see `Options::show_millis`.
+    Will clear cached `diff` values.
+    */
+    pub fn set_show_millis(&mut self, show_millis: bool) -> &mut Self {
+        self.options.show_millis = show_millis;
+        self.clear_cache();
+        self.process();
+        self
     }
 
     /**
-    Get years between `DateTime` and `DateTime` given for context. Can be chained to string together
-    multiple values of your choosing; `cache` must be clear before doing this.
+    Set how many fractional digits seconds are displayed with, see `Options::subsec_digits`. Will
+    clear cached `diff` values.
+    */
+    pub fn set_subsec_digits(&mut self, subsec_digits: Option<usize>) -> &mut Self {
+        self.options.subsec_digits = subsec_digits;
+        self.clear_cache();
+        self.process();
+        self
+    }
 
-    ```rust
-    let dt = Local::now();
-    let mut elapsed = Elapsed::custom(dt);
-    println!("{}", elapsed.years_and().months_and().weeks());
-    elapsed.clear_cache();
-    // This one is silly.
-    println!("{}", elapsed.years_and().seconds());
-    ```
+    /** Set when only the largest `TimeFrame` is displayed, see `Options::over_mode`. */
+    pub fn set_over_mode(&mut self, over_mode: Option<TimeFrame>) -> &mut Self {
+        self.options.over_mode = over_mode;
+        self
+    }
 
-    Results in `1y 6m 2w` the first time, or something silly the second time.
+    /** Set which `TimeFrame`s are displayed, and in what order, see `Options::frame_order`. */
+    pub fn set_frame_order(&mut self, order: Vec<TimeFrame>) -> &mut Self {
+        self.options.frame_order = Some(order);
+        self
+    }
 
-    Will panic if you do something extra silly like `elapsed.seconds_and().years()` (even though it
-    doesn't seem _that_ silly.) I have to enforce _some_ rules.
-    */
-    pub fn years_and(&mut self) -> &mut Self {
-        self.protected_insert(TimeFrame::Year, self.num_years());
+    /** Set what the context represents, see `Options::reference_label`. */
+    pub fn set_reference_label(&mut self, label: impl Into<String>) -> &mut Self {
+        self.options.reference_label = Some(label.into());
         self
     }
 
-    /** Get number of months. */
-    pub fn num_months(&self) -> u64 {
-        floor((self.duration.num_weeks() / 4) as f64, 0) as u64
+    /** Set the vague words `humanize` uses for counts, see `VagueConfig`. */
+    pub fn set_vague(&mut self, vague: Option<VagueConfig>) -> &mut Self {
+        self.options.vague = vague;
+        self
     }
 
-    /**
-    Get months between `DateTime` and `DateTime` given for context as `elapsed` style tuple.
-    */
-    pub fn months(&mut self) -> TimeFrameTuple {
-        let mut months = self.num_months();
-        if let Some(years) = &self.cache[TimeFrame::Year as usize] {
-            months -= years.1;
-        }
-        Self::as_tuple(TimeFrame::Month, months)
+    /** Set the progress past which `humanize` says `almost`, see `Options::almost_threshold`. */
+    pub fn set_almost_threshold(&mut self, threshold: Option<f64>) -> &mut Self {
+        self.options.almost_threshold = threshold;
+        self
     }
 
-    /**  */
-    pub fn months_and(&mut self) -> &mut Self {
-        let months = self.months().1 - (self.num_years() * 12);
-        self.protected_insert(TimeFrame::Month, months);
+    /** Set whether leap seconds are counted, see `Options::count_leap_seconds`. */
+    pub fn set_count_leap_seconds(&mut self, count_leap_seconds: bool) -> &mut Self {
+        self.options.count_leap_seconds = count_leap_seconds;
         self
     }
 
     /**
-    Get weeks between `DateTime` and `DateTime` given for context as `elapsed` style tuple.
-
-    Chrono provides a method to get numeric value alone, which is exposed by `Elapsed` struct
-    `duration` field.
+    Set whether signed calendar fields are displayed, see `Options::signed_components`. Will clear
+    cached `diff` values.
     */
-    pub fn weeks(&mut self) -> TimeFrameTuple {
-        Self::as_tuple(TimeFrame::Week, self.duration.num_weeks() as u64)
+    pub fn set_signed_components(&mut self, signed_components: bool) -> &mut Self {
+        self.options.signed_components = signed_components;
+        self.clear_cache();
+        self.process();
+        self
     }
 
     /**
-    Get days between `DateTime` and `DateTime` given for context as `elapsed` style tuple.
-
-    Chrono provides a method to get numeric value alone, which is exposed by `Elapsed` struct
-    `duration` field.
+    Set the `TimeFrame`s `process` never populates, see `Options::excluded`. Will clear cached
+    `diff` values.
     */
-    pub fn days(&mut self) -> TimeFrameTuple {
-        Self::as_tuple(TimeFrame::Day, self.duration.num_days() as u64)
+    pub fn exclude(&mut self, frames: &[TimeFrame]) -> &mut Self {
+        self.options.excluded = frames.to_vec();
+        self.clear_cache();
+        self.process();
+        self
+    }
+
+    /** Set the subject `describe` leads with, see `Options::describe_prefix`. */
+    pub fn set_describe_prefix(&mut self, prefix: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.options.describe_prefix = prefix.into();
+        self
+    }
+
+    /** Set when `smart` switches to displaying a date, see `Options::smart_cutoff`. */
+    pub fn set_smart_cutoff(&mut self, smart_cutoff: Duration) -> &mut Self {
+        self.options.smart_cutoff = smart_cutoff;
+        self
+    }
+
+    /** Set the `strftime` style format `smart` displays dates with, see `Options::smart_format`. */
+    pub fn set_smart_format(&mut self, smart_format: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.options.smart_format = smart_format.into();
+        self
     }
 
     /**
-    Get hours between `DateTime` and `DateTime` given for context as `elapsed` style tuple.
+    Set the separator used to group thousands, see `Options::digit_grouping`. Will clear cached
+    `diff` values.
+    */
+    pub fn set_digit_grouping(&mut self, digit_grouping: Option<char>) -> &mut Self {
+        self.options.digit_grouping = digit_grouping;
+        self.clear_cache();
+        self.process();
+        self
+    }
 
-    Chrono provides a method to get numeric value alone, which is exposed by `Elapsed` struct
-    `duration` field.*/
-    pub fn hours(&mut self) -> TimeFrameTuple {
-        Self::as_tuple(TimeFrame::Hour, self.duration.num_hours() as u64)
+    /** Set the `Elapsed`'s date. Will clear cached `diff` values. */
+    #[allow(deprecated)]
+    pub fn set_date(&mut self, date: Date<Local>) {
+        self.date = date;
+        self.datetime = date.and_hms(0, 0, 0);
+        self.duration = self.datetime.signed_duration_since(self.datetime_context);
+        self.passed = self.datetime.lt(&self.datetime_context);
+        self.clear_cache();
+        self.process();
     }
 
     /**
-    Get minutes between `DateTime` and `DateTime` given for context as `elapsed` style tuple.
+    Populate `cache` with contextually aware `TimeFrame`s, see `Breakdown::process`. Discards
+    "irrelevant" time frames, e.g. `1y 6mo` as opposed to `1y 6mo 2w 4d`. See
+    `Options::signed_components` for the alternative.
+    */
+    pub fn process(&mut self) {
+        if self.options.signed_components {
+            self.process_signed();
+        } else {
+            Breakdown::process(self);
+        }
+    }
 
-    Chrono provides a method to get numeric value alone, which is exposed by `Elapsed` struct
-    `duration` field.
+    /**
+    Helper fn to populate `cache` with the signed difference of each calendar field, e.g. `1y -2mo`,
+    see `Options::signed_components`. Zero valued fields are skipped.
     */
-    pub fn minutes(&mut self) -> TimeFrameTuple {
-        Self::as_tuple(TimeFrame::Minute, self.duration.num_minutes() as u64)
+    fn process_signed(&mut self) {
+        let (from, to) = (
+            self.datetime_context.naive_local(),
+            self.datetime.naive_local(),
+        );
+        let fields = [
+            (TimeFrame::Year, to.year() as i64 - from.year() as i64),
+            (TimeFrame::Month, to.month() as i64 - from.month() as i64),
+            (TimeFrame::Day, to.day() as i64 - from.day() as i64),
+            (TimeFrame::Hour, to.hour() as i64 - from.hour() as i64),
+            (TimeFrame::Minute, to.minute() as i64 - from.minute() as i64),
+            (TimeFrame::Second, to.second() as i64 - from.second() as i64),
+        ];
+        for (tf, val) in fields.iter().filter(|(_, val)| *val != 0) {
+            let (text, abs) = self.options.as_tuple(*tf, val.unsigned_abs());
+            let text = if *val < 0 {
+                format!("-{}", text).into()
+            } else {
+                text
+            };
+            self.cache[*tf as usize] = Some((text, abs));
+        }
     }
 
     /**
-    Get seconds between `DateTime` and `DateTime` given for context as `elapsed` style tuple.
+    Populate `cache` with exactly the given `TimeFrame`s, in any order, bypassing the heuristic
+    `process` uses. Each value has the larger time frames subtracted, e.g. `[Day, Hour]` gives
+    `15d 4hr` rather than `15d 364hr`.
+    */
+    pub fn process_fixed(&mut self, frames: &[TimeFrame]) {
+        let mut frames = frames.to_vec();
+        frames.sort_unstable_by(|a, b| b.cmp(a));
+        frames.dedup();
+        self.clear_cache();
+        for tf in frames {
+            self.cache_insert(tf, self.remainder(tf));
+        }
+    }
 
-    Chrono provides a method to get numeric value alone, which is exposed by `Elapsed` struct
-    `duration` field.
+    /**
+    Get the largest `TimeFrame` in the `cache`, i.e. the granularity `process` settled on, or `None`
+    if the `cache` is empty.
     */
-    pub fn seconds(&mut self) -> TimeFrameTuple {
-        const SEC_IN_MIN: u64 = 60;
-        const SEC_IN_HOUR: u64 = 3600;
-        const SEC_IN_DAY: u64 = 86400;
-        const SEC_IN_WEEK: u64 = 604800;
-        const SEC_IN_MONTH: u64 = 2629800;
-        const SEC_IN_YEAR: u64 = 31557600;
-        Self::as_tuple(TimeFrame::Second, self.duration.num_seconds() as u64)
+    pub fn granularity(&self) -> Option<TimeFrame> {
+        self.cache
+            .iter()
+            .rposition(Option::is_some)
+            .map(TimeFrame::from_index)
     }
 
-    /** Helper fn to get an elapsed style tuple. */
-    fn as_tuple(tf: TimeFrame, val: u64) -> TimeFrameTuple {
-        (format!("{}{}", val, tf.abbrev()).into(), val)
+    /** Get the entry for a `TimeFrame` in the `cache`, or `None` if it hasn't been populated. */
+    pub fn get(&self, tf: TimeFrame) -> Option<&TimeFrameTuple> {
+        self.cache.get(tf as usize).and_then(Option::as_ref)
     }
 
     /**
-    This fn is intended to be used similarly to chaining, like so:
+    Borrow the string in the `cache` when exactly one `TimeFrame` is populated, e.g. `3d`, without
+    allocating. There's no `in` or `ago`; `None` when zero, or multiple, time frames are populated.
+    */
+    pub fn as_str(&self) -> Option<&str> {
+        let mut populated = self.cache.iter().flatten();
+        match (populated.next(), populated.next()) {
+            (Some(tuple), None) => Some(&tuple.0),
+            _ => None,
+        }
+    }
 
-    ```rust
-    let date = self.seconds_and().through_til(&TimeFrame::Months);
-    println!("{}", date);
-    ```
+    /**
+    Display without separating `TimeFrame`s, e.g. `in 3y2mo4d`, as opposed to `in 3y 2mo 4d`. Can be
+    parsed back with `parse_elapsed`.
+    */
+    pub fn compact(&self) -> String {
+        let body = self.options.parts(&self.cache).concat();
+        self.options
+            .frame(displayed_direction(self.duration), &body)
+    }
 
-    Resulting in seconds, minutes, hours, days, weeks and months being set in `cache`, and then
-    subsequently printed as `(in) 3y 2w 4d 12hr 32min 42sec (ago)`.
+    /**
+    Get the formatted string for each `TimeFrame` in the `cache`, in display order, e.g. `["3y",
+    "2mo"]`, for joining yourself. There's no `in` or `ago`.
     */
-    pub fn through_til(&mut self, tf: &TimeFrame) -> &mut Self {
-        todo!()
+    pub fn tokens(&self) -> Vec<String> {
+        let parts = self.options.parts(&self.cache);
+        parts.into_iter().map(str::to_string).collect()
     }
 
-    /** Create a clone of our `cache` containing the values at time of collection. */
-    pub fn collect(&self) -> Cache {
-        self.cache.clone()
+    /** Get the raw value for a `TimeFrame` in the `cache`, or `None` if it isn't populated. */
+    pub fn cached_value(&self, tf: TimeFrame) -> Option<u64> {
+        self.get(tf).map(|tuple| tuple.1)
     }
-}
 
-impl Display for Elapsed {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut vec: Vec<&str> = Vec::new();
-        if let Some(years) = &self.cache[TimeFrame::Year as usize] {
-            vec.push(&years.0);
-        }
-        if let Some(months) = &self.cache[TimeFrame::Month as usize] {
-            vec.push(&months.0);
-        }
-        if let Some(weeks) = &self.cache[TimeFrame::Week as usize] {
-            vec.push(&weeks.0);
-        }
-        if let Some(days) = &self.cache[TimeFrame::Day as usize] {
-            vec.push(&days.0);
-        }
-        if let Some(hours) = &self.cache[TimeFrame::Hour as usize] {
-            vec.push(&hours.0);
-        }
-        if let Some(minutes) = &self.cache[TimeFrame::Minute as usize] {
-            vec.push(&minutes.0);
-        }
-        if let Some(seconds) = &self.cache[TimeFrame::Second as usize] {
-            vec.push(&seconds.0);
+    /** Helper fn to insert a value for a `TimeFrame` into the cache, see `try_cache_insert`. */
+    pub fn cache_insert(&mut self, k: TimeFrame, v: u64) {
+        Breakdown::cache_insert(self, k, v);
+    }
+
+    /** Insert a value for a `TimeFrame` into the cache, see `Breakdown::try_cache_insert`. */
+    pub fn try_cache_insert(&mut self, k: TimeFrame, v: u64) -> Result<(), ElapsedError> {
+        Breakdown::try_cache_insert(self, k, v)
+    }
+
+    /**
+    Helper fn to keep the user in check before throwing wack values in the `cache`. Unless
+    `any_order` is set, then cached time frames are recalculated largest first.
+    */
+    fn protected_insert(&mut self, k: TimeFrame, v: u64) {
+        if self.options.any_order {
+            let mut frames: Vec<TimeFrame> = (0..self.cache.len())
+                .filter(|i| self.cache[*i].is_some())
+                .map(TimeFrame::from_index)
+                .collect();
+            frames.push(k);
+            frames.sort_unstable_by(|a, b| b.cmp(a));
+            frames.dedup();
+            self.clear_cache();
+            for tf in frames {
+                self.cache_insert(tf, self.remainder(tf));
+            }
+            return;
         }
-        if let Some(milliseconds) = &self.cache[TimeFrame::MilliSecond as usize] {
-            vec.push(&milliseconds.0);
+        for i in 0..k as usize {
+            if self.cache[i].is_some() {
+                panic!(
+                    "Please, let's try and be civil. Make your calls from largest `TimeFrame` to smallest."
+                )
+            }
         }
+        self.cache_insert(k, v);
+    }
 
-        if self.passed {
-            write!(f, "{} ago", vec.join(" "))
-        } else {
-            write!(f, "in {}", vec.join(" "))
+    /** Set the marker of an approximate breakdown, see `Options::approximate_marker`. */
+    pub fn set_approximate_marker(&mut self, marker: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.options.approximate_marker = Some(marker.into());
+        self
+    }
+
+    /** Allow the `*_and` methods to be chained in any order, see `Options::any_order`. */
+    pub fn allow_any_order(&mut self) -> &mut Self {
+        self.options.any_order = true;
+        self
+    }
+
+    /**
+    Clear the `cache`, data only; `Options` set via the `set_*` methods are kept, so are preserved
+    by the setters that clear and reprocess, such as `set_datetime`.
+    */
+    pub fn clear_cache(&mut self) {
+        if !self.cache.is_empty() {
+            self.cache = Cache::default();
         }
     }
-}
 
-impl From<DateTime<Local>> for Elapsed {
-    /** Construct _from_ localised `DateTime`. */
-    fn from(datetime: DateTime<Local>) -> Self {
-        Self::new(datetime)
+    /**
+    Reset `Options` to their defaults, undoing the `set_*` methods, then reprocess. Will clear
+    cached `diff` values.
+    */
+    pub fn reconfigure(&mut self) -> &mut Self {
+        self.options = Options::default();
+        self.clear_cache();
+        self.process();
+        self
     }
-}
 
-impl From<Date<Local>> for Elapsed {
-    /** Construct _from_ localised `Date`. */
-    fn from(date: Date<Local>) -> Self {
-        Self::new_from_date(date)
+    /** Set the `DateTime` used for context to `now`. Will clear cached `diff` values. */
+    pub fn refresh(&mut self) -> &mut Self {
+        self.set_datetime_context(Local::now())
     }
-}
 
-impl From<DateTime<Utc>> for Elapsed {
-    /** Construct _from_ UTC `DateTime`. */
-    fn from(datetime: DateTime<Utc>) -> Self {
-        Self::new_then_localize(datetime)
+    /**
+    Refresh an `Elapsed` shared between threads, e.g. via `Arc<Mutex<Elapsed>>`, and display it. A
+    poisoned lock is recovered, there's nothing a panicking thread could've left half done.
+    */
+    pub fn refresh_shared(shared: &Mutex<Self>) -> String {
+        let mut elapsed = shared
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        elapsed.refresh().to_string()
     }
-}
 
-impl From<Date<Utc>> for Elapsed {
-    /** Construct _from_ UTC `Date`. */
-    fn from(date: Date<Utc>) -> Self {
-        Self::new_from_date_then_localize(date)
+    /** Whether the differences in time are exactly equal, unlike `==` sub-second noise counts. */
+    pub fn exact_eq(&self, other: &Self) -> bool {
+        self.duration == other.duration
     }
-}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(u8)]
-pub enum TimeFrame {
-    /*
-    Tempted to leave millisecond out because by virtue this crate isn't dealing with micro and nano
-    seconds, but milliseconds are useful in the Unix world. A millisecond to us wouldn't ever be
-    more than 60 however.
+    /**
+    Restore the object to its freshly constructed state; `duration` and `passed` are recomputed from
+    the stored `DateTime`s and the `cache` is repopulated by `process`, undoing any chaining or
+    `process_fixed`. `Options` are kept.
     */
-    MilliSecond = 0,
-    Second = 1,
-    Minute = 2,
-    Hour = 3,
-    Day = 4,
-    Week = 5,
-    Month = 6,
-    Year = 7,
-    // Decade ...
-}
+    pub fn reset(&mut self) -> &mut Self {
+        self.duration = self.datetime.signed_duration_since(self.datetime_context);
+        self.passed = self.datetime.lt(&self.datetime_context);
+        self.clear_cache();
+        self.process();
+        self
+    }
 
-impl From<TimeFrame> for String {
-    /** Return `String` from `TimeFrame`. */
-    fn from(tf: TimeFrame) -> Self {
-        match tf {
-            TimeFrame::MilliSecond => String::from("millisecond(s)"),
-            TimeFrame::Second => String::from("second(s)"),
-            TimeFrame::Minute => String::from("minute(s)"),
-            TimeFrame::Hour => String::from("hour(s)"),
-            TimeFrame::Day => String::from("day(s)"),
-            TimeFrame::Week => String::from("week(s)"),
-            TimeFrame::Month => String::from("month(s)"),
-            TimeFrame::Year => String::from("year(s)"),
+    /**
+    Get someone's age, given their `birthdate`, as whole years alongside a breakdown such as
+    `30y 2mo`. Unlike `num_years`, this uses calendar months, so birthdays land on the right day.
+    */
+    pub fn age(birthdate: DateTime<Local>) -> (u64, String) {
+        let elapsed = Self::custom(birthdate);
+        let months = Self::calendar_months(elapsed.datetime, elapsed.datetime_context);
+        let (years, months) = (months / 12, months % 12);
+        let breakdown = format!(
+            "{} {}",
+            elapsed.as_tuple(TimeFrame::Year, years).0,
+            elapsed.as_tuple(TimeFrame::Month, months).0
+        );
+        (years, breakdown)
+    }
+
+    /**
+    Helper fn to get the number of whole calendar months between two `DateTime`s, in any order. A
+    month has elapsed once the same day of the month, and time of day, has been reached. The day is
+    clamped to the end of shorter months, see `add_months`, so Feb 29th has its anniversary on Feb
+    28th in years that aren't leap years, same as `is_anniversary`.
+    */
+    fn calendar_months(a: DateTime<Local>, b: DateTime<Local>) -> u64 {
+        let (from, to) = if a <= b { (a, b) } else { (b, a) };
+        let (from, to) = (from.naive_local(), to.naive_local());
+        let months =
+            (to.year() - from.year()) as i64 * 12 + to.month() as i64 - from.month() as i64;
+        match months.max(0) as u64 {
+            months if months > 0 && Self::add_months(from, months) > to => months - 1,
+            months => months,
         }
     }
-}
 
-impl TryFrom<&str> for TimeFrame {
-    type Error = &'static str;
-    /** Attempt to parse `str` to `TimeFrame`. */
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value.to_lowercase().trim() {
-            "millisecond" | "ms" => Ok(Self::MilliSecond),
-            "second" | "sec" | "s" => Ok(Self::Second),
-            "minute" | "min" => Ok(Self::Minute),
-            "hour" | "hr" | "h" => Ok(Self::Hour),
-            "day" | "d" => Ok(Self::Day),
-            "week" | "wk" | "w" => Ok(Self::Week),
-            "month" | "mon" => Ok(Self::Month),
-            "year" | "yr" | "y" => Ok(Self::Year),
-            _ => Err("Invalid or ambiguous string for `elapsed::TimeFrame`"),
+    /**
+    Display `target` relative to each of the `contexts`, e.g. for a table. One object is reused for
+    every context, rather than constructing one each.
+    */
+    pub fn batch_against(target: DateTime<Local>, contexts: &[DateTime<Local>]) -> Vec<String> {
+        let mut elapsed = match contexts.first() {
+            Some(context) => Self::custom_with_context(target, *context),
+            None => return Vec::new(),
+        };
+        contexts
+            .iter()
+            .map(|context| elapsed.set_datetime_context(*context).to_string())
+            .collect()
+    }
+
+    /**
+    Whether `DateTime` and the `DateTime` given for context share the same month and day, e.g. a
+    birthday. Feb 29th falls on Feb 28th in years that aren't leap years.
+    */
+    pub fn is_anniversary(&self) -> bool {
+        let (from, to) = self.ordered_dates();
+        Self::anniversary_in(from, to.year()) == to
+    }
+
+    /**
+    Get the number of whole years between the earlier date and its most recent anniversary, on or
+    before the later date. Time of day is ignored, see `is_anniversary`.
+    */
+    pub fn years_since_anniversary(&self) -> u64 {
+        let (from, to) = self.ordered_dates();
+        let years = (to.year() - from.year()) as u64;
+        if Self::anniversary_in(from, to.year()) > to {
+            years.saturating_sub(1)
+        } else {
+            years
         }
     }
-}
 
-impl From<TimeFrame> for char {
-    /** Return `char` from `TimeFrame`. */
-    fn from(tf: TimeFrame) -> Self {
-        match tf {
-            TimeFrame::MilliSecond => 'm',
-            TimeFrame::Second => 's',
-            TimeFrame::Minute => 'm',
-            TimeFrame::Hour => 'h',
-            TimeFrame::Day => 'd',
-            TimeFrame::Week => 'w',
-            TimeFrame::Month => 'm',
-            TimeFrame::Year => 'y',
+    /**
+    Get how many times `wd` occurs strictly between the local dates of `DateTime` and the `DateTime`
+    given for context, in either direction, e.g. 3 Mondays over 3 weeks from a Wednesday. Neither
+    endpoint's own day is counted.
+    */
+    pub fn weekday_count(&self, wd: Weekday) -> u64 {
+        let (from, to) = self.ordered_dates();
+        let days = to.signed_duration_since(from).num_days() - 1;
+        if days <= 0 {
+            return 0;
+        }
+        let first = (from.weekday().num_days_from_monday() + 1) % 7;
+        let offset = (7 + wd.num_days_from_monday() - first) % 7;
+        match days - offset as i64 {
+            left if left > 0 => ((left - 1) / 7 + 1) as u64,
+            _ => 0,
         }
     }
-}
 
-impl TryFrom<char> for TimeFrame {
-    type Error = &'static str;
-    /** Attempt to parse `char` to `TimeFrame`. clashes will fail (m for ms, min, month). */
-    fn try_from(value: char) -> Result<Self, Self::Error> {
-        match value.to_ascii_lowercase() {
-            's' => Ok(Self::Second),
-            'h' => Ok(Self::Hour),
-            'd' => Ok(Self::Day),
-            'w' => Ok(Self::Week),
-            'y' => Ok(Self::Year),
-            _ => Err("Invalid or ambiguous char for `elapsed::TimeFrame`"),
+    /** Helper fn to get the local dates of `DateTime` and its context, earliest first. */
+    fn ordered_dates(&self) -> (NaiveDate, NaiveDate) {
+        let a = self.datetime.naive_local().date();
+        let b = self.datetime_context.naive_local().date();
+        if a <= b {
+            (a, b)
+        } else {
+            (b, a)
         }
     }
-}
 
-pub trait Abbreviate {
-    fn abbrev(&self) -> &'static str;
-    fn abbrev_short(&self) -> &'static str;
-}
+    /** Helper fn to get the anniversary of `date` in `year`, Feb 29th falls on Feb 28th. */
+    fn anniversary_in(date: NaiveDate, year: i32) -> NaiveDate {
+        date.with_year(year)
+            .or_else(|| NaiveDate::from_ymd_opt(year, 2, 28))
+            .expect("Feb 28th exists in every year a date does")
+    }
 
-impl Abbreviate for TimeFrame {
-    /** Abbreviate `TimeFrame` to reasonably short string. */
-    fn abbrev(&self) -> &'static str {
-        match self {
-            TimeFrame::MilliSecond => "ms",
-            TimeFrame::Second => "sec",
-            TimeFrame::Minute => "min",
-            TimeFrame::Hour => "hr",
-            TimeFrame::Day => "d",
-            TimeFrame::Week => "w",
-            TimeFrame::Month => "m",
-            TimeFrame::Year => "y",
+    /** Display time until, or since, a named `Event`, e.g. `Launch in 3d`. */
+    pub fn to_event(event: &Event) -> String {
+        event.render(&Self::new(event.at))
+    }
+
+    /**
+    Get the rate of `count` events over the difference in time, per the largest `TimeFrame` that
+    fits in it at least once, e.g. `(4.0, TimeFrame::Day)` for 12 events over 3 days. Infinite when
+    there's no difference in time.
+    */
+    pub fn rate(&self, count: u64) -> (f64, TimeFrame) {
+        let millis = self.magnitude().num_milliseconds().unsigned_abs();
+        let tf = Self::coarsest(millis);
+        (count as f64 / (millis as f64 / tf.millis() as f64), tf)
+    }
+
+    /**
+    Get the largest `TimeFrame` that fits in the difference in time, its whole count, and the
+    fraction, from `0.0` to `1.0`, of the way to the next count, e.g. `(TimeFrame::Year, 1, 0.5)`
+    for a year and a half. Handy for gauges. Months and years use their average length.
+    */
+    pub fn coarsest_with_fraction(&self) -> (TimeFrame, u64, f64) {
+        let millis = self.magnitude().num_milliseconds().unsigned_abs();
+        let tf = Self::coarsest(millis);
+        let fraction = (millis % tf.millis()) as f64 / tf.millis() as f64;
+        (tf, millis / tf.millis(), fraction)
+    }
+
+    /**
+    Describe the difference in time as a recurring period, e.g. `daily` for about 24 hours, for
+    scheduler UIs. Within 5% of an hour, day, week, month or year, otherwise `None`. Months and
+    years use their average length.
+    */
+    pub fn periodicity(&self) -> Option<&'static str> {
+        let millis = self.magnitude().num_milliseconds().unsigned_abs();
+        PERIODS
+            .iter()
+            .find(|(tf, _)| millis.abs_diff(tf.millis()) <= tf.millis() / 20)
+            .map(|(_, word)| *word)
+    }
+
+    /** Helper fn to get the largest `TimeFrame` that fits in `millis` at least once. */
+    fn coarsest(millis: u64) -> TimeFrame {
+        let mut tf = TimeFrame::Year;
+        while millis < tf.millis() {
+            match tf.smaller() {
+                Some(smaller) => tf = smaller,
+                None => break,
+            }
         }
+        tf
     }
 
     /**
-    Abbreviate `TimeFrame` to a still sensibly short string, mostly just a char except when there
-    are clashes (ms, min, month).
+    Get the signed difference in time in whole seconds. When `count_leap_seconds` is set, any leap
+    seconds inserted in between are included, see `LEAP_SECONDS`.
     */
-    fn abbrev_short(&self) -> &'static str {
-        match self {
-            TimeFrame::MilliSecond => "ms",
-            TimeFrame::Second => "s",
-            TimeFrame::Minute => "min",
-            TimeFrame::Hour => "h",
-            TimeFrame::Day => "d",
-            TimeFrame::Week => "w",
-            TimeFrame::Month => "m",
-            TimeFrame::Year => "y",
+    pub fn total_seconds_with_leaps(&self) -> i64 {
+        let seconds = self.duration.num_seconds();
+        if !self.options.count_leap_seconds {
+            return seconds;
+        }
+        let (from, to) = if self.datetime <= self.datetime_context {
+            (self.datetime, self.datetime_context)
+        } else {
+            (self.datetime_context, self.datetime)
+        };
+        let leaps = LEAP_SECONDS
+            .iter()
+            .filter_map(|(y, m, d)| NaiveDate::from_ymd_opt(*y, *m, *d)?.succ_opt())
+            .map(|after| Utc.from_utc_datetime(&Self::midnight(after)))
+            .filter(|after| from < *after && *after <= to)
+            .count() as i64;
+        if self.duration < Duration::zero() {
+            seconds - leaps
+        } else {
+            seconds + leaps
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /**
+    Get the difference in time as whole weeks and the remaining 0-6 days, e.g. `(32, 3)` for 227
+    days, regardless of the heuristic `process` uses.
+    */
+    pub fn weeks_and_days(&self) -> (u64, u64) {
+        let days = self.duration.num_days().unsigned_abs();
+        (days / 7, days % 7)
+    }
 
-    #[test]
-    fn print_elapsed_since_birth() {
-        let dt_str = "1993-10-30T04:20:00Z";
-        let past_dt = dt_str
-            .parse::<DateTime<Local>>()
-            .expect("failed to parse str as `DateTime<Local>`");
-        let elapsed = Elapsed::new(past_dt);
-        println!("{}", elapsed)
+    /**
+    Get the signed number of calendar days between the `DateTime` given for context and
+    `DateTime`, counting date boundaries crossed rather than 24 hour periods. Unaffected by DST, and
+    11pm to 1am the next day is a day.
+    */
+    pub fn calendar_days(&self) -> i64 {
+        let from = self.datetime_context.naive_local().date();
+        let to = self.datetime.naive_local().date();
+        to.signed_duration_since(from).num_days()
     }
 
-    #[test]
-    fn print_elapsed_since_recent() {
-        let now = Local::now();
-        let recent_dt = now - Duration::minutes(20);
-        let elapsed = Elapsed::new(recent_dt);
-        println!("{}", elapsed)
+    /**
+    Display in calendar terms when close, `tomorrow` or `yesterday`, see `calendar_days`. On the
+    same day the time of day difference is kept, e.g. `2hr earlier today` or `30min later today`.
+    Otherwise falls back to `Display`, e.g. `3w ago`.
+    */
+    pub fn contextual(&self) -> String {
+        let body = || self.options.parts(&self.cache).join(" ");
+        match (self.calendar_days(), displayed_direction(self.duration)) {
+            (_, Direction::Now) => self.options.zero_text.to_string(),
+            (0, Direction::Future) => format!("{} later today", body()),
+            (0, Direction::Past) => format!("{} earlier today", body()),
+            (1, _) => "tomorrow".to_string(),
+            (-1, _) => "yesterday".to_string(),
+            _ => self.to_string(),
+        }
+    }
+
+    /**
+    Get the signed difference in time as fractional days, e.g. `1.5` for 36 hours. Unlike
+    `num_days`, the sub-day fraction is kept.
+    */
+    pub fn total_days_f64(&self) -> f64 {
+        match self.duration.num_nanoseconds() {
+            Some(nanos) => nanos as f64 / 86_400_000_000_000.0,
+            /* Too large for nanosecond precision, that's fine, milliseconds will do. */
+            None => self.duration.num_milliseconds() as f64 / 86_400_000.0,
+        }
+    }
+
+    /** Whether the difference in time is in the past, future, or exactly now, see `Direction`. */
+    pub fn direction(&self) -> Direction {
+        Breakdown::direction(self)
+    }
+
+    /** Write to any `core::fmt::Write` sink without building a `String`, see `Breakdown::render`. */
+    pub fn render<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+        Breakdown::render(self, w)
+    }
+
+    /**
+    Get how long until the displayed breakdown would change, given the smallest populated
+    `TimeFrame`, so a live display can sleep exactly this long. E.g. `5min` due in 5 minutes 30
+    seconds changes in 30 seconds, and a whole `5min` a full minute from now. Zero when the `cache`
+    is empty. Months and years use their average length, so are approximate.
+    */
+    pub fn time_until_change(&self) -> Duration {
+        let smallest = match self.cache.iter().position(Option::is_some) {
+            Some(i) => TimeFrame::from_index(i),
+            None => return Duration::zero(),
+        };
+        let unit = smallest.millis();
+        let remainder = self.duration.num_milliseconds().unsigned_abs() % unit;
+        /*
+        Counting down to a future date shrinks the remainder, counting up grows it. Exactly on a
+        boundary, counting down has a full unit to go.
+        */
+        let millis = match self.direction() {
+            Direction::Future if remainder > 0 => remainder,
+            _ => unit - remainder,
+        };
+        Duration::milliseconds(millis as i64)
+    }
+
+    /**
+    Get the next occurrence of `DateTime` repeating `every` period, i.e. the first repetition
+    strictly after the `DateTime` given for context, e.g. tomorrow's instance of a daily standup
+    that's already happened today. A future `DateTime` hasn't occurred yet so is returned as is, as
+    is any `DateTime` given a period that isn't positive.
+    */
+    pub fn next_occurrence(&self, every: Duration) -> DateTime<Local> {
+        if every <= Duration::zero() || self.datetime > self.datetime_context {
+            return self.datetime;
+        }
+        /* Nanoseconds, so periods below a millisecond are still whole steps. */
+        let period = wide_nanos(every);
+        let since = wide_nanos(self.datetime_context - self.datetime);
+        let offset = (since / period + 1) * period;
+        self.datetime
+            + Duration::seconds((offset / 1_000_000_000) as i64)
+            + Duration::nanoseconds((offset % 1_000_000_000) as i64)
+    }
+
+    /**
+    Get the sub-second component of `duration` in nanoseconds, e.g. `500_000_000` for `1.5s`. The
+    breakdown otherwise only ever deals in whole units, see `Options::subsec_digits`.
+    */
+    pub fn subsec_nanos(&self) -> u32 {
+        subsec_nanos(self.duration)
+    }
+
+    /**
+    Get the signed difference in time in whole milliseconds, `None` if it overflows `i64`. `process`
+    counts in milliseconds too, and falls back to whole seconds when this is `None`.
+    */
+    pub fn checked_num_milliseconds(&self) -> Option<i64> {
+        checked_millis(self.duration)
+    }
+
+    /**
+    Get the signed difference in time in whole microseconds, `None` if it overflows `i64`, which it
+    does beyond roughly 292,000 years.
+    */
+    pub fn checked_num_microseconds(&self) -> Option<i64> {
+        self.duration.num_microseconds()
+    }
+
+    /**
+    Get the signed difference in time in whole nanoseconds, `None` if it overflows `i64`, which it
+    does beyond roughly 292 years.
+    */
+    pub fn checked_num_nanoseconds(&self) -> Option<i64> {
+        self.duration.num_nanoseconds()
+    }
+
+    /**
+    Describe the difference in time as a full sentence, in words, for screen readers and the like,
+    e.g. `This event is due in 3 days, 2 hours and 30 minutes.` or `This event happened 2 weeks
+    ago.` Zero valued time frames are left out. See `set_describe_prefix`.
+    */
+    pub fn describe(&self) -> String {
+        let mut words: Vec<String> = self
+            .cache
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(i, tuple)| match tuple {
+                Some((_, val)) if *val > 0 => Some(pluralize(TimeFrame::from_index(i), *val)),
+                _ => None,
+            })
+            .collect();
+        let body = match words.pop() {
+            Some(last) if words.is_empty() => last,
+            Some(last) => format!("{} and {}", words.join(", "), last),
+            None => self.options.zero_text.to_string(),
+        };
+        let prefix = &self.options.describe_prefix;
+        match displayed_direction(self.duration) {
+            Direction::Past => format!("{} happened {} ago.", prefix, body),
+            Direction::Now => format!("{} is due {}.", prefix, body),
+            Direction::Future => format!("{} is due in {}.", prefix, body),
+        }
+    }
+
+    /**
+    Describe the range between the `DateTime` given for context and `DateTime`, formatted using
+    a `strftime` style `fmt`, followed by the breakdown, e.g. `between 2024-01-01 and 2024-01-15
+    (2w)`.
+    */
+    pub fn describe_range(&self, fmt: &str) -> String {
+        let body = match self.options.parts(&self.cache) {
+            parts if parts.is_empty() => self.options.zero_text.to_string(),
+            parts => parts.join(" "),
+        };
+        format!(
+            "between {} and {} ({})",
+            self.datetime_context.format(fmt),
+            self.datetime.format(fmt),
+            body
+        )
+    }
+
+    /**
+    Display relative, followed by `DateTime` formatted with a `strftime` style `fmt`, e.g. `3d ago
+    (2024-01-05)` given `%Y-%m-%d`.
+    */
+    pub fn relative_and_absolute(&self, fmt: &str) -> String {
+        format!("{} ({})", self, self.datetime.format(fmt))
+    }
+
+    /**
+    Format the `DateTime` given for context and `DateTime` with a `strftime` style `fmt`, earliest
+    first, joined by an en dash, e.g. `2019–2022` given `%Y`. Handy for timelines.
+    */
+    pub fn span_label(&self, fmt: &str) -> String {
+        let (from, to) = if self.datetime <= self.datetime_context {
+            (self.datetime, self.datetime_context)
+        } else {
+            (self.datetime_context, self.datetime)
+        };
+        format!("{}–{}", from.format(fmt), to.format(fmt))
+    }
+
+    /** Get the `Elapsed` closest to its context, past or future, or `None` if it's empty. */
+    pub fn nearest(elapsed: &[Elapsed]) -> Option<&Elapsed> {
+        elapsed.iter().min_by_key(|e| e.magnitude())
+    }
+
+    /** Get the `Elapsed` farthest from its context, past or future, or `None` if it's empty. */
+    pub fn farthest(elapsed: &[Elapsed]) -> Option<&Elapsed> {
+        elapsed.iter().max_by_key(|e| e.magnitude())
+    }
+
+    /** Helper fn to get the absolute `duration`. */
+    fn magnitude(&self) -> Duration {
+        if self.duration < Duration::zero() {
+            -self.duration
+        } else {
+            self.duration
+        }
+    }
+
+    /**
+    Display relative time when recent, like `5min ago`, or the date when it's further away than
+    `smart_cutoff`, like `on Jan 5, 2024`. Much like GitHub or Slack.
+    */
+    pub fn smart(&self) -> String {
+        if self.magnitude() < self.options.smart_cutoff {
+            self.to_string()
+        } else {
+            self.datetime.format(&self.options.smart_format).to_string()
+        }
+    }
+
+    /** Write the display string straight to `w`, handy for exporting in bulk. */
+    pub fn write_to<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        write!(w, "{}", self)
+    }
+
+    /**
+    Display only the largest non-zero `TimeFrame`, with its short abbreviation and no `in` or `ago`,
+    e.g. `3d` or `2h`. Handy for notification badges. Independent of the `cache`.
+    */
+    pub fn humanize_short(&self) -> String {
+        let millis = self.magnitude().num_milliseconds().unsigned_abs();
+        let mut tf = Some(TimeFrame::Year);
+        while let Some(current) = tf {
+            let count = millis / current.millis();
+            if count > 0 {
+                return format!("{}{}", count, current.abbrev_short());
+            }
+            tf = current.smaller();
+        }
+        self.options.zero_text.to_string()
+    }
+
+    /**
+    Get each `TimeFrame` in the `cache`, largest first, alongside the abbreviation we display it
+    with and its full name, e.g. `(TimeFrame::Month, "mo", "month")`. Useful for legends.
+    */
+    pub fn legend(&self) -> Vec<(TimeFrame, &'static str, &'static str)> {
+        self.cache
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, tuple)| tuple.is_some())
+            .map(|(i, _)| {
+                let tf = TimeFrame::from_index(i);
+                (tf, self.options.abbrev(tf), tf.name())
+            })
+            .collect()
+    }
+
+    /**
+    Get each populated `TimeFrame` in the `cache`, largest first, as a `Duration` with the sign of
+    the difference in time, e.g. `(TimeFrame::Day, Duration::days(3))` for the `3d` part. Months and
+    years use their average length, see `TimeFrame::seconds_in`, so the sum only approximates
+    `duration`; as does any smaller time frame `process` dropped.
+    */
+    pub fn component_durations(&self) -> Vec<(TimeFrame, Duration)> {
+        let sign = if self.passed { -1 } else { 1 };
+        self.cache
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(i, tuple)| {
+                let tf = TimeFrame::from_index(i);
+                let millis = tuple.as_ref()?.1.saturating_mul(tf.millis());
+                Some((tf, Duration::milliseconds(sign * millis as i64)))
+            })
+            .collect()
+    }
+
+    /**
+    Display the difference in time in the format `std::time::Duration` uses for `Debug`, e.g.
+    `259200s` for 3 days or `-1.5s`, negative when passed. A machine format for logs, unlike
+    `Display`.
+    */
+    pub fn duration_string(&self) -> String {
+        let sign = if self.passed { "-" } else { "" };
+        let std = self.magnitude().to_std().unwrap_or_default();
+        format!("{}{:?}", sign, std)
+    }
+
+    /**
+    Get a small JSON object describing the breakdown, without pulling in `serde`, e.g.
+    `{"passed":true,"years":3,"months":2,"display":"3y 2mo ago"}`. Time frames are listed largest
+    first, using their plural names.
+    */
+    pub fn to_json(&self) -> String {
+        let mut json = format!("{{\"passed\":{}", self.passed);
+        for (i, tuple) in self.cache.iter().enumerate().rev() {
+            if let Some((_, val)) = tuple {
+                let tf = TimeFrame::from_index(i);
+                let _ = write!(json, ",\"{}s\":{}", tf.name(), val);
+            }
+        }
+        let _ = write!(
+            json,
+            ",\"display\":\"{}\"}}",
+            escape_json(&self.to_string())
+        );
+        json
+    }
+
+    /**
+    Display a rough, human friendly, difference in time such as `in about an hour` or `3 days ago`.
+    Rounding is identical for past and future, see `Options::almost_threshold` for `almost 30
+    years`.
+    */
+    pub fn humanize(&self) -> String {
+        let millis = self.magnitude().num_milliseconds().unsigned_abs();
+        let seconds = millis / 1000;
+        let body = match HUMANIZE_BUCKETS.iter().find(|(below, _)| seconds < *below) {
+            Some((_, Phrase::Fixed(phrase))) => phrase.to_string(),
+            Some((_, Phrase::Count(tf))) => {
+                let exact = millis as f64 / tf.millis() as f64;
+                let count = exact.round() as u64;
+                let almost = self.options.almost_threshold.filter(|t| exact.fract() > *t);
+                if almost.is_some() {
+                    let body = pluralize(*tf, exact.ceil() as u64);
+                    return self.options.frame(
+                        displayed_direction(self.duration),
+                        &format!("almost {}", body),
+                    );
+                }
+                match self
+                    .options
+                    .vague
+                    .as_ref()
+                    .and_then(|vague| vague.word(count))
+                {
+                    Some(word) => format!("{} {}s", word, tf.name()),
+                    None => pluralize(*tf, count),
+                }
+            }
+            None => unreachable!("the last bucket is `u64::MAX`"),
+        };
+        self.options
+            .frame(displayed_direction(self.duration), &body)
+    }
+
+    /**
+    Get number of whole calendar years, in either direction. A year has elapsed once the same day of
+    the year, and time of day, has been reached; rather than after 52 weeks, which is only 364 days.
+    */
+    pub fn num_years(&self) -> u64 {
+        Self::calendar_months(self.datetime, self.datetime_context) / 12
+    }
+
+    /** Get years between `DateTime` and `DateTime` given for context as `elapsed` style tuple. */
+    pub fn years(&mut self) -> TimeFrameTuple {
+        self.as_tuple(TimeFrame::Year, self.num_years())
+    }
+
+    /**
+    Get years between `DateTime` and `DateTime` given for context. Can be chained to string together
+    multiple values of your choosing; `cache` must be clear before doing this.
+
+    ```rust
+    use chrono::{Duration, Local};
+    use chrono_elapsed::Elapsed;
+
+    let dt = Local::now() + Duration::weeks(80);
+    let mut elapsed = Elapsed::custom(dt);
+    println!("{}", elapsed.years_and().months_and().weeks_and());
+    elapsed.clear_cache();
+    // This one is silly.
+    println!("{}", elapsed.years_and().seconds_and());
+    ```
+
+    Results in `1y 6mo 2w` the first time, or something silly the second time.
+
+    Will panic if you do something extra silly like `elapsed.seconds_and().years_and()` (even though
+    it doesn't seem _that_ silly.) I have to enforce _some_ rules, unless you `allow_any_order`.
+    */
+    pub fn years_and(&mut self) -> &mut Self {
+        self.protected_insert(TimeFrame::Year, self.num_years());
+        self
+    }
+
+    /** Get number of whole calendar months, in either direction, see `num_years`. */
+    pub fn num_months(&self) -> u64 {
+        Self::calendar_months(self.datetime, self.datetime_context)
+    }
+
+    /**
+    Get the number of whole calendar months in the entire difference in time, in either direction.
+    Unlike `months`, cached years aren't subtracted, so the `cache` makes no difference.
+    */
+    pub fn total_months(&self) -> u64 {
+        Self::calendar_months(self.datetime, self.datetime_context)
+    }
+
+    /**
+    Get the number of whole `tf` in the entire difference in time, in either direction, e.g. `90`
+    minutes for an hour and a half. Months and years are calendar based, see `total_months`.
+    */
+    pub fn numeric_in(&self, tf: TimeFrame) -> u64 {
+        match tf {
+            TimeFrame::Year => self.num_years(),
+            TimeFrame::Month => self.total_months(),
+            _ => self.magnitude().num_milliseconds().unsigned_abs() / tf.millis(),
+        }
+    }
+
+    /** Display `numeric_in` alone, e.g. `90` with no abbreviation or grouping, for CSV export. */
+    pub fn numeric_string_in(&self, tf: TimeFrame) -> String {
+        self.numeric_in(tf).to_string()
+    }
+
+    /**
+    Get the number of whole weeks in the entire difference in time, in either direction. Unlike
+    `weeks_and`, cached larger time frames aren't subtracted, so the `cache` makes no difference.
+    */
+    pub fn total_weeks(&self) -> u64 {
+        self.duration.num_weeks().unsigned_abs()
+    }
+
+    /**
+    Get months between `DateTime` and `DateTime` given for context as `elapsed` style tuple. Cached
+    years are subtracted, and months are calendar months.
+    */
+    pub fn months(&mut self) -> TimeFrameTuple {
+        self.as_tuple(TimeFrame::Month, self.remainder(TimeFrame::Month))
+    }
+
+    /**
+    Get months between `DateTime` and `DateTime` given for context. Can be chained, see
+    `years_and`.
+    */
+    pub fn months_and(&mut self) -> &mut Self {
+        self.protected_insert(TimeFrame::Month, self.remainder(TimeFrame::Month));
+        self
+    }
+
+    /**
+    Get weeks between `DateTime` and `DateTime` given for context as `elapsed` style tuple.
+
+    Chrono provides a method to get numeric value alone, which is exposed by `Elapsed` struct
+    `duration` field.
+    */
+    pub fn weeks(&mut self) -> TimeFrameTuple {
+        let millis = self.magnitude().num_milliseconds().unsigned_abs();
+        let weeks = self.options.week_rounding.unwrap_or_default();
+        self.as_tuple(TimeFrame::Week, weeks.div(millis, TimeFrame::Week.millis()))
+    }
+
+    /**
+    Get weeks between `DateTime` and `DateTime` given for context. Can be chained, larger time frames
+    in the `cache` are subtracted.
+    */
+    pub fn weeks_and(&mut self) -> &mut Self {
+        self.protected_insert(TimeFrame::Week, self.remainder(TimeFrame::Week));
+        self
+    }
+
+    /**
+    Get days between `DateTime` and `DateTime` given for context as `elapsed` style tuple.
+
+    Chrono provides a method to get numeric value alone, which is exposed by `Elapsed` struct
+    `duration` field.
+    */
+    pub fn days(&mut self) -> TimeFrameTuple {
+        self.as_tuple(TimeFrame::Day, self.duration.num_days().unsigned_abs())
+    }
+
+    /**
+    Get days between `DateTime` and `DateTime` given for context. Can be chained, larger time frames
+    in the `cache` are subtracted.
+    */
+    pub fn days_and(&mut self) -> &mut Self {
+        self.protected_insert(TimeFrame::Day, self.remainder(TimeFrame::Day));
+        self
+    }
+
+    /**
+    Get hours between `DateTime` and `DateTime` given for context as `elapsed` style tuple.
+
+    Chrono provides a method to get numeric value alone, which is exposed by `Elapsed` struct
+    `duration` field.*/
+    pub fn hours(&mut self) -> TimeFrameTuple {
+        self.as_tuple(TimeFrame::Hour, self.duration.num_hours().unsigned_abs())
+    }
+
+    /**
+    Get hours between `DateTime` and `DateTime` given for context. Can be chained, larger time frames
+    in the `cache` are subtracted.
+    */
+    pub fn hours_and(&mut self) -> &mut Self {
+        self.protected_insert(TimeFrame::Hour, self.remainder(TimeFrame::Hour));
+        self
+    }
+
+    /**
+    Get minutes between `DateTime` and `DateTime` given for context as `elapsed` style tuple.
+
+    Chrono provides a method to get numeric value alone, which is exposed by `Elapsed` struct
+    `duration` field.
+    */
+    pub fn minutes(&mut self) -> TimeFrameTuple {
+        self.as_tuple(
+            TimeFrame::Minute,
+            self.duration.num_minutes().unsigned_abs(),
+        )
+    }
+
+    /**
+    Get minutes between `DateTime` and `DateTime` given for context. Can be chained, larger time frames
+    in the `cache` are subtracted.
+    */
+    pub fn minutes_and(&mut self) -> &mut Self {
+        self.protected_insert(TimeFrame::Minute, self.remainder(TimeFrame::Minute));
+        self
+    }
+
+    /**
+    Get seconds between `DateTime` and `DateTime` given for context as `elapsed` style tuple.
+
+    Chrono provides a method to get numeric value alone, which is exposed by `Elapsed` struct
+    `duration` field.
+    */
+    #[allow(dead_code)]
+    pub fn seconds(&mut self) -> TimeFrameTuple {
+        const SEC_IN_MIN: u64 = 60;
+        const SEC_IN_HOUR: u64 = 3600;
+        const SEC_IN_DAY: u64 = 86400;
+        const SEC_IN_WEEK: u64 = 604800;
+        const SEC_IN_MONTH: u64 = 2629800;
+        const SEC_IN_YEAR: u64 = 31557600;
+        self.as_tuple(
+            TimeFrame::Second,
+            self.duration.num_seconds().unsigned_abs(),
+        )
+    }
+
+    /**
+    Get seconds between `DateTime` and `DateTime` given for context. Can be chained, larger time frames
+    in the `cache` are subtracted.
+    */
+    pub fn seconds_and(&mut self) -> &mut Self {
+        self.protected_insert(TimeFrame::Second, self.remainder(TimeFrame::Second));
+        self
+    }
+
+    /**
+    Get milliseconds between `DateTime` and `DateTime` given for context as `elapsed` style tuple.
+    */
+    pub fn milliseconds(&mut self) -> TimeFrameTuple {
+        let millis = self.duration.num_milliseconds().unsigned_abs();
+        self.as_tuple(TimeFrame::MilliSecond, millis)
+    }
+
+    /**
+    Get milliseconds between `DateTime` and `DateTime` given for context. Can be chained, larger
+    time frames in the `cache` are subtracted.
+    */
+    pub fn milliseconds_and(&mut self) -> &mut Self {
+        self.protected_insert(
+            TimeFrame::MilliSecond,
+            self.remainder(TimeFrame::MilliSecond),
+        );
+        self
+    }
+
+    /**
+    Helper fn to get the value of a `TimeFrame` once larger `TimeFrame`s in the `cache` have been
+    subtracted. Years and months are stepped through on the calendar, then the rest is measured on
+    the local wall clock too, so crossing DST doesn't leave an hour over. Without cached years or
+    months, smaller time frames are exact.
+    */
+    fn remainder(&self, tf: TimeFrame) -> u64 {
+        let cached = |k: TimeFrame| match self.get(k) {
+            Some(tuple) if k > tf => tuple.1,
+            _ => 0,
+        };
+        match tf {
+            /* Calendar accurate, so months stay within 0-11 of the years. */
+            TimeFrame::Year => self.num_years(),
+            TimeFrame::Month => Self::calendar_months(self.datetime, self.datetime_context)
+                .saturating_sub(cached(TimeFrame::Year) * 12),
+            _ => {
+                let months = cached(TimeFrame::Year) * 12 + cached(TimeFrame::Month);
+                let mut millis = self.wall_clock_millis(months);
+                for k in &[
+                    TimeFrame::Week,
+                    TimeFrame::Day,
+                    TimeFrame::Hour,
+                    TimeFrame::Minute,
+                    TimeFrame::Second,
+                ] {
+                    millis = millis.saturating_sub(cached(*k) * k.millis());
+                }
+                match (tf, self.options.week_rounding) {
+                    (TimeFrame::Week, Some(rounding)) => rounding.div(millis, tf.millis()),
+                    _ => millis / tf.millis(),
+                }
+            }
+        }
+    }
+
+    /**
+    Helper fn to get the milliseconds left once `months` calendar months have been stepped through
+    from the earlier `DateTime`, measured on the local wall clock. Exact when `months` is zero.
+    */
+    fn wall_clock_millis(&self, months: u64) -> u64 {
+        if months == 0 {
+            return self.magnitude().num_milliseconds().unsigned_abs();
+        }
+        let (from, to) = self.wall_clock_span();
+        let millis = to
+            .signed_duration_since(Self::add_months(from, months))
+            .num_milliseconds();
+        millis.max(0) as u64
+    }
+
+    /** Helper fn to get the earlier and later `DateTime`s on the local wall clock. */
+    fn wall_clock_span(&self) -> (NaiveDateTime, NaiveDateTime) {
+        let (from, to) = if self.datetime <= self.datetime_context {
+            (self.datetime, self.datetime_context)
+        } else {
+            (self.datetime_context, self.datetime)
+        };
+        (from.naive_local(), to.naive_local())
+    }
+
+    /**
+    Helper fn to step a `NaiveDateTime` forward a number of calendar months. The day of the month is
+    clamped, so Jan 31st plus a month is the last day of February.
+    */
+    fn add_months(naive: NaiveDateTime, months: u64) -> NaiveDateTime {
+        let month0 = naive.month0() as i64 + months as i64;
+        let year = naive.year() + (month0 / 12) as i32;
+        let month = (month0 % 12) as u32 + 1;
+        (1..=naive.day())
+            .rev()
+            .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+            .map_or(naive, |date| date.and_time(naive.time()))
+    }
+
+    /** Helper fn to get an elapsed style tuple. */
+    fn as_tuple(&self, tf: TimeFrame, val: u64) -> TimeFrameTuple {
+        self.options.as_tuple(tf, val)
+    }
+
+    /**
+    This fn is intended to be used similarly to chaining, like so:
+
+    ```rust
+    use chrono::{Duration, Local};
+    use chrono_elapsed::{Elapsed, TimeFrame};
+
+    let mut elapsed = Elapsed::custom(Local::now() + Duration::weeks(60));
+    println!("{}", elapsed.seconds_and().through_til(&TimeFrame::Month));
+    ```
+
+    Resulting in seconds, minutes, hours, days, weeks and months being set in `cache`, and then
+    subsequently printed as `(in) 13mo 3w 4d 12hr 32min 42sec (ago)`. Every `TimeFrame` between the
+    smallest already in the `cache`, and `tf`, is populated, see `process_fixed`.
+    */
+    pub fn through_til(&mut self, tf: &TimeFrame) -> &mut Self {
+        self.through_til_frames(tf);
+        self
+    }
+
+    /**
+    Same as `through_til`, but returns the `TimeFrame`s populated in the `cache`, largest first, so
+    you can check what's covered.
+    */
+    pub fn through_til_frames(&mut self, tf: &TimeFrame) -> Vec<TimeFrame> {
+        let mut frames: Vec<TimeFrame> = (0..self.cache.len())
+            .filter(|i| self.cache[*i].is_some())
+            .map(TimeFrame::from_index)
+            .collect();
+        let smallest = frames.first().copied().unwrap_or(*tf);
+        let (mut current, largest) = if smallest <= *tf {
+            (smallest, *tf)
+        } else {
+            (*tf, smallest)
+        };
+        frames.push(current);
+        while current < largest {
+            match current.larger() {
+                Some(larger) => current = larger,
+                None => break,
+            }
+            frames.push(current);
+        }
+        self.process_fixed(&frames);
+        frames.sort_unstable_by(|a, b| b.cmp(a));
+        frames.dedup();
+        frames
+    }
+
+    /** Borrow the `cache` for read only inspection, unlike `collect` nothing is cloned. */
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    /** Create a clone of our `cache` containing the values at time of collection. */
+    pub fn collect(&self) -> Cache {
+        self.cache.clone()
+    }
+}
+
+impl Display for Elapsed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_breakdown(f)
+    }
+}
+
+impl Add<Duration> for Elapsed {
+    type Output = Elapsed;
+    /** Shift the `DateTime` forward, the context is unchanged. */
+    fn add(mut self, rhs: Duration) -> Self::Output {
+        let datetime = self.datetime + rhs;
+        self.set_datetime(datetime);
+        self
+    }
+}
+
+impl Sub<Duration> for Elapsed {
+    type Output = Elapsed;
+    /** Shift the `DateTime` back, the context is unchanged. */
+    fn sub(mut self, rhs: Duration) -> Self::Output {
+        let datetime = self.datetime - rhs;
+        self.set_datetime(datetime);
+        self
+    }
+}
+
+impl std::iter::Sum<Duration> for Elapsed {
+    /** Sum `Duration`s into an `Elapsed` relative to `now`, see `new_from_duration`. */
+    fn sum<I: Iterator<Item = Duration>>(iter: I) -> Self {
+        Self::new_from_duration(iter.fold(Duration::zero(), |total, d| total + d))
+    }
+}
+
+impl PartialEq for Elapsed {
+    /**
+    Equal when the differences in time match to the whole second, so two objects built from
+    `Local::now()` a few microseconds apart are still equal. See `exact_eq`.
+    */
+    fn eq(&self, other: &Self) -> bool {
+        self.duration.num_seconds() == other.duration.num_seconds()
+    }
+}
+
+impl Breakdown for Elapsed {
+    fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    fn passed(&self) -> bool {
+        self.passed
+    }
+
+    fn options(&self) -> &Options {
+        &self.options
+    }
+
+    fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    fn cache_mut(&mut self) -> &mut Cache {
+        &mut self.cache
+    }
+
+    fn calendar_span(&self) -> Option<(u64, u64, u64)> {
+        let months = Self::calendar_months(self.datetime, self.datetime_context);
+        let (from, _) = self.wall_clock_span();
+        let next_month = Self::add_months(from, months + 1)
+            .signed_duration_since(Self::add_months(from, months))
+            .num_milliseconds();
+        Some((
+            months,
+            self.wall_clock_millis(months),
+            next_month.max(0) as u64,
+        ))
+    }
+}
+
+impl From<DateTime<Local>> for Elapsed {
+    /** Construct _from_ localised `DateTime`. */
+    fn from(datetime: DateTime<Local>) -> Self {
+        Self::new(datetime)
+    }
+}
+
+#[allow(deprecated)]
+impl From<Date<Local>> for Elapsed {
+    /** Construct _from_ localised `Date`. */
+    fn from(date: Date<Local>) -> Self {
+        Self::new_from_date(date)
+    }
+}
+
+impl From<DateTime<Utc>> for Elapsed {
+    /** Construct _from_ UTC `DateTime`. */
+    fn from(datetime: DateTime<Utc>) -> Self {
+        Self::new_then_localize(datetime)
+    }
+}
+
+#[allow(deprecated)]
+impl From<Date<Utc>> for Elapsed {
+    /** Construct _from_ UTC `Date`. */
+    fn from(date: Date<Utc>) -> Self {
+        Self::new_from_date_then_localize(date)
+    }
+}
+
+impl TryFrom<&str> for Elapsed {
+    type Error = &'static str;
+    /** Attempt to parse an RFC 3339 `str`, e.g. `2024-01-01T00:00:00Z`, then localize it. */
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match DateTime::parse_from_rfc3339(value.trim()) {
+            Ok(datetime) => Ok(Self::new_then_localize(datetime.with_timezone(&Utc))),
+            Err(_) => Err("Invalid RFC 3339 string for `elapsed::Elapsed`"),
+        }
+    }
+}
+
+impl TryFrom<i64> for Elapsed {
+    type Error = ElapsedError;
+    /** Attempt to treat an `i64` as a Unix timestamp in seconds, see `from_unix`. */
+    fn try_from(secs: i64) -> Result<Self, Self::Error> {
+        Self::from_unix(secs)
+    }
+}
+
+/**
+Helper fn to get the sub-second component of a `Duration` in nanoseconds, always positive, e.g.
+`500_000_000` for both `1.5s` and `-1.5s`.
+*/
+fn subsec_nanos(duration: Duration) -> u32 {
+    let subsec = duration - Duration::seconds(duration.num_seconds());
+    subsec.num_nanoseconds().unwrap_or(0).unsigned_abs() as u32
+}
+
+/** Helper fn to get `now`, failing when the `Local` offset can't be determined. */
+fn try_now() -> Result<DateTime<Local>, ElapsedError> {
+    try_now_from(Utc::now(), |local| Local.offset_from_local_datetime(local))
+}
+
+/**
+Helper fn to get `utc` as `Local`, checking the offset against `lookup` of the local time it gives,
+so a timezone that can't place, or disagrees about, its own local time is caught.
+*/
+fn try_now_from<F>(utc: DateTime<Utc>, lookup: F) -> Result<DateTime<Local>, ElapsedError>
+where
+    F: Fn(&NaiveDateTime) -> LocalResult<FixedOffset>,
+{
+    let now = utc.with_timezone(&Local);
+    let matches = match lookup(&now.naive_local()) {
+        LocalResult::Single(offset) => offset == *now.offset(),
+        LocalResult::Ambiguous(a, b) => a == *now.offset() || b == *now.offset(),
+        LocalResult::None => false,
+    };
+    if matches {
+        Ok(now)
+    } else {
+        Err(ElapsedError::UnknownLocalOffset)
+    }
+}
+
+/**
+Helper fn to get a `Duration` in whole milliseconds, `None` rather than overflowing `i64`. Built
+from whole seconds so it never relies on `chrono` keeping its range within milliseconds.
+*/
+fn checked_millis(duration: Duration) -> Option<i64> {
+    let subsec = (duration - Duration::seconds(duration.num_seconds())).num_milliseconds();
+    duration
+        .num_seconds()
+        .checked_mul(1000)?
+        .checked_add(subsec)
+}
+
+/**
+Helper fn to get the `Direction` a difference in time is displayed with. Differences below
+`INSTANT_MILLIS` are displayed as `zero_text`, so they're `Now`, unlike `Breakdown::direction`.
+*/
+fn displayed_direction(duration: Duration) -> Direction {
+    match checked_millis(duration) {
+        Some(millis) if millis.unsigned_abs() < INSTANT_MILLIS => Direction::Now,
+        _ => duration.into(),
+    }
+}
+
+/** Helper fn to get a `Duration` in nanoseconds, widened to `i128` so it can't overflow. */
+fn wide_nanos(duration: Duration) -> i128 {
+    let subsec = (duration - Duration::seconds(duration.num_seconds())).num_nanoseconds();
+    duration.num_seconds() as i128 * 1_000_000_000 + subsec.unwrap_or(0) as i128
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Elapsed {
+    /** Construct _from_ a `time` crate `OffsetDateTime`, see `from_time_offset`. */
+    fn from(datetime: time::OffsetDateTime) -> Self {
+        Self::from_time_offset(datetime)
+    }
+}
+
+/**
+Private supertrait of `Breakdown`, so it can't be implemented outside of this crate and the
+accessors to the `cache` aren't an extension point.
+*/
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::Elapsed {}
+    impl Sealed for super::DurationElapsed {}
+}
+
+/**
+Shared processing and display logic for anything holding a `Duration` and a `Cache`, namely
+`Elapsed` and the lighter `DurationElapsed`. Sealed, it's implemented for those two only.
+*/
+pub trait Breakdown: private::Sealed {
+    /** Difference in time, see `Elapsed::duration`. */
+    fn duration(&self) -> Duration;
+    /** Whether the difference in time is in the past, see `Elapsed::passed`. */
+    fn passed(&self) -> bool;
+    /** Display & processing `Options`, as set via the `set_*` fns. */
+    fn options(&self) -> &Options;
+    /** `TimeFrame`s processed so far, see `Elapsed::cache`. */
+    fn cache(&self) -> &Cache;
+    /** Mutable access to the `cache`, used whilst processing. */
+    fn cache_mut(&mut self) -> &mut Cache;
+
+    /** Whether the difference in time is in the past, future, or exactly now. */
+    fn direction(&self) -> Direction {
+        self.duration().into()
+    }
+
+    /**
+    Whole calendar months in the difference in time, the milliseconds left over, and the length in
+    milliseconds of the month they're left over in, if there are `DateTime`s to step through. `None`
+    means months are counted in weeks, see `process`.
+    */
+    fn calendar_span(&self) -> Option<(u64, u64, u64)> {
+        None
+    }
+
+    /**
+    Populate `cache` with contextually aware `TimeFrame`s. Discards "irrelevant" time frames, for
+    example if date is due in more than a year, we'll only store `1y 6mo`, not `1y 6mo 2w 4d`.
+
+    Exact boundaries always land on the larger `TimeFrame`, with no zero valued remainders, bar
+    minutes below 5 minutes which always accompany seconds. Differences below `INSTANT_MILLIS` are
+    displayed as `zero_text`, and below a second as milliseconds, unless `subsec_digits` is set:
+
+    | Difference   | Displayed   |
+    |--------------|-------------|
+    | 5ms          | `now`       |
+    | 500ms        | `500ms`     |
+    | 1s           | `0min 1sec` |
+    | 5min         | `5min`      |
+    | 60min        | `1hr`       |
+    | 4h           | `4hr`       |
+    | 24h          | `1d`        |
+    | 7d           | `1w`        |
+    | 1 month      | `1mo`       |
+    | 12 months    | `1y`        |
+
+    These boundaries are the default `ProcessThresholds`, see `Options::thresholds`. Months and
+    years are calendar based when there's a `calendar_span`, like `Elapsed::num_years`, so Jan 1st
+    to Feb 1st is `1mo` and 28 days into January is `4w`. Starting late in a month, the day is
+    clamped to the end of shorter months, so Jan 31st to Mar 30th (2023) is `1mo 4w 2d`, counting on
+    from Feb 28th. Otherwise, e.g. for a `DurationElapsed`, `weeks_per_month` weeks make a month, 28
+    days by default, and 336 days make a year.
+    */
+    fn process(&mut self) {
+        /*
+        All absolute values, we can assume values are below zero later on when we check `passed`,
+        whilst we're building the str that represents time elapsed, we aren't concerned with past or
+        future.
+
+        Whole weeks, days, etc. are counted according to `rounding`, at every rollup point, so with
+        `RoundNearest` 23 hours is already a day. Remainders saturate at zero when rounded up.
+        */
+        let diff = self.duration();
+        let rounding = self.options().rounding;
+        /* Whole seconds will do when milliseconds overflow, there's no sub-second part then. */
+        let checked = checked_millis(diff);
+        let milliseconds = checked.map_or_else(
+            || diff.num_seconds().unsigned_abs().saturating_mul(1000),
+            i64::unsigned_abs,
+        );
+        let count = |tf: TimeFrame| rounding.div(milliseconds, tf.millis());
+        let weeks = self
+            .options()
+            .week_rounding
+            .unwrap_or(rounding)
+            .div(milliseconds, TimeFrame::Week.millis());
+        let days = count(TimeFrame::Day);
+        let hours = count(TimeFrame::Hour);
+        let minutes = count(TimeFrame::Minute);
+        let seconds = count(TimeFrame::Second);
+        let thresholds = self.options().thresholds;
+        let calendar = self.calendar_span();
+        /* Weeks only roll up to months when there's no calendar to count them on. */
+        let weeks_per_month = match calendar {
+            Some(_) => u64::MAX,
+            None => thresholds.weeks_per_month.max(1),
+        };
+
+        /* Excluded months are rolled into weeks, so there's no need to count them. */
+        let months_excluded = self.options().excluded.contains(&TimeFrame::Month);
+        if let Some((months, millis_remaining, month_millis)) =
+            calendar.filter(|(months, _, _)| *months > 0 && !months_excluded)
+        {
+            /* Whole calendar months, then weeks and days from what's left on the wall clock. */
+            let weeks = self
+                .options()
+                .week_rounding
+                .unwrap_or(rounding)
+                .div(millis_remaining, TimeFrame::Week.millis());
+            let weeks_millis = weeks * TimeFrame::Week.millis();
+            let days_remaining = rounding.div(
+                millis_remaining.saturating_sub(weeks_millis),
+                TimeFrame::Day.millis(),
+            );
+            /* Rounded up to the end of the month, e.g. 27d of February is 4w, which is 1mo. */
+            let (months, weeks, days_remaining) =
+                if weeks_millis + days_remaining * TimeFrame::Day.millis() >= month_millis {
+                    (months + 1, 0, 0)
+                } else {
+                    (months, weeks, days_remaining)
+                };
+            let months_per_year = thresholds.months_per_year.max(1);
+            let years = rounding.div(months, months_per_year);
+            if years == 0
+            /* Less than a year: */
+            {
+                self.cache_insert(TimeFrame::Month, months);
+                if weeks > 0 {
+                    self.cache_insert(TimeFrame::Week, weeks);
+                }
+                if days_remaining > 0 {
+                    self.cache_insert(TimeFrame::Day, days_remaining);
+                }
+            } else
+            /* Potentially multiple years */
+            {
+                let months_remaining = months.saturating_sub(years * months_per_year);
+                self.cache_insert(TimeFrame::Year, years);
+                if months_remaining > 0 {
+                    self.cache_insert(TimeFrame::Month, months_remaining);
+                }
+            }
+        } else if weeks > 0 {
+            /* Remaining days that `weeks` rounded off, e.g. 38d is 5w (+3d). */
+            let millis_remaining = milliseconds.saturating_sub(weeks * TimeFrame::Week.millis());
+            let days_remaining = rounding.div(millis_remaining, TimeFrame::Day.millis());
+            if weeks < weeks_per_month {
+                /* In n weeks, simples. */
+                self.cache_insert(TimeFrame::Week, weeks);
+                if days_remaining > 0 {
+                    self.cache_insert(TimeFrame::Day, days_remaining);
+                }
+            } else
+            /* Months: */
+            {
+                /* Round down for months, easy for us to add remaining weeks. */
+                let months = rounding.div(weeks, weeks_per_month);
+                /*
+                Get remaining weeks, e.g.:
+                6w [1mo (+2w, rounded off)] - (1mo * 4w) = 2w
+                */
+                let weeks_remaining = weeks.saturating_sub(months * weeks_per_month);
+                if months < thresholds.months_per_year
+                /* Less than a year: */
+                {
+                    self.cache_insert(TimeFrame::Month, months);
+                    if weeks_remaining > 0 {
+                        self.cache_insert(TimeFrame::Week, weeks_remaining);
+                    }
+                    if days_remaining > 0 {
+                        self.cache_insert(TimeFrame::Day, days_remaining);
+                    }
+                } else
+                /* Potentially multiple years */
+                {
+                    let months_per_year = thresholds.months_per_year.max(1);
+                    let years = rounding.div(months, months_per_year);
+                    let months_remaining = months.saturating_sub(years * months_per_year);
+                    self.cache_insert(TimeFrame::Year, years);
+                    if months_remaining > 0 {
+                        self.cache_insert(TimeFrame::Month, months_remaining);
+                    }
+                }
+            }
+        } else if days > 0
+        /* and weeks are 0. */
+        {
+            self.cache_insert(TimeFrame::Day, days);
+        } else if hours >= thresholds.hours_alone
+        /* and days are 0. */
+        {
+            self.cache_insert(TimeFrame::Hour, hours);
+        } else if hours > 0
+        /* and in less than `hours_alone`, 4 hours by default. */
+        {
+            self.cache_insert(TimeFrame::Hour, hours);
+            let minutes_remaining = minutes.saturating_sub(hours * 60);
+            if minutes_remaining > 0 {
+                self.cache_insert(TimeFrame::Minute, minutes_remaining);
+            }
+        } else if minutes >= thresholds.minutes_alone
+        /* and in less than an hour. */
+        {
+            self.cache_insert(TimeFrame::Minute, minutes);
+        } else if milliseconds < INSTANT_MILLIS {
+            /* An instant, displayed as `zero_text`, there's nothing worth caching. */
+        } else if milliseconds < 1000 && self.options().subsec_digits.is_none() {
+            /* and less than a second away, e.g. `250ms`. */
+            self.cache_insert(TimeFrame::MilliSecond, milliseconds);
+        } else
+        /* and less 5 minutes away, so there's always seconds. */
+        {
+            /* Pads left with 0s: format!("{:0>1}:{:0>1}s", min, sec_remaining) */
+            self.cache_insert(TimeFrame::Minute, minutes);
+            let seconds_remaining = seconds.saturating_sub(minutes * 60);
+            if let (Some(precision), Some(_)) = (self.options().subsec_digits, checked) {
+                let nanos = subsec_nanos(diff);
+                let tuple = self
+                    .options()
+                    .as_subsec_tuple(seconds_remaining, nanos, precision);
+                self.cache_mut()[TimeFrame::Second as usize] = Some(tuple);
+            } else {
+                self.cache_insert(TimeFrame::Second, seconds_remaining);
+            }
+            if self.options().show_millis
+                && self.options().subsec_digits.is_none()
+                && checked.is_some()
+            {
+                let millis_remaining = milliseconds.saturating_sub(seconds * 1000);
+                self.cache_insert(TimeFrame::MilliSecond, millis_remaining);
+            }
+        }
+
+        /*
+        Roll excluded time frames into the next smaller one, largest first so they cascade, e.g.
+        excluded months become weeks, which become days if weeks are excluded too.
+        */
+        let excluded = self.options().excluded.clone();
+        for i in (0..self.cache().len()).rev() {
+            let tf = TimeFrame::from_index(i);
+            if !excluded.contains(&tf) {
+                continue;
+            }
+            if let (Some((_, val)), Some(smaller)) = (self.cache_mut()[i].take(), tf.smaller()) {
+                let existing = self.cache()[smaller as usize].as_ref().map_or(0, |t| t.1);
+                self.cache_insert(smaller, existing + val * (tf.millis() / smaller.millis()));
+            }
+        }
+
+        /* Pad with zeros down from the largest `TimeFrame`, see `pad_units`. */
+        if let Some(largest) = self.cache().iter().rposition(Option::is_some) {
+            let smallest = largest.saturating_sub(self.options().pad_units.saturating_sub(1));
+            for i in smallest..largest {
+                if self.cache()[i].is_none() && !excluded.contains(&TimeFrame::from_index(i)) {
+                    self.cache_insert(TimeFrame::from_index(i), 0);
+                }
+            }
+        }
+    }
+
+    /**
+    Helper fn to insert a value for a `TimeFrame` into the cache. Panics if the `TimeFrame` has no
+    slot, which can't happen while `Cache` is sized to the number of `TimeFrame` variants.
+    */
+    fn cache_insert(&mut self, k: TimeFrame, v: u64) {
+        self.try_cache_insert(k, v)
+            .expect("`Cache` must have a slot for every `TimeFrame`");
+    }
+
+    /**
+    Insert a value for a `TimeFrame` into the cache. `TimeFrame` discriminants index the `cache`,
+    an error is returned rather than panicking if one is out of bounds.
+    */
+    fn try_cache_insert(&mut self, k: TimeFrame, v: u64) -> Result<(), ElapsedError> {
+        if k as usize >= self.cache().len() {
+            return Err(ElapsedError::CacheIndexOutOfBounds(k));
+        }
+        let tuple = self.options().as_tuple(k, v);
+        self.cache_mut()[k as usize] = Some(tuple);
+        Ok(())
+    }
+
+    /**
+    Write the `cache`, framed as past or future, to any `core::fmt::Write` sink, e.g. a fixed
+    capacity buffer on embedded targets. No `String` is built along the way; output is identical to
+    `Display`, bar padding.
+    */
+    fn render<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+        self.write_breakdown(w, None)
+    }
+
+    /**
+    Helper fn to write the `cache` framed as past or future, shared by `render` and `Display`.
+    `precision` caps the number of `TimeFrame`s, e.g. `{:.2}` for `1y 6mo`.
+    */
+    fn write_breakdown<W: core::fmt::Write>(
+        &self,
+        w: &mut W,
+        precision: Option<usize>,
+    ) -> core::fmt::Result {
+        let options = self.options();
+        match options.affixes(displayed_direction(self.duration())) {
+            Some((prefix, suffix)) => {
+                w.write_str(prefix)?;
+                match options.over(self.cache()) {
+                    Some(body) => w.write_str(&body)?,
+                    None => {
+                        let mut parts = options.parts(self.cache());
+                        if let Some(precision) = precision {
+                            parts.truncate(precision);
+                        }
+                        if let Some(marker) = &options.approximate_marker {
+                            if !options.is_exact(self.cache(), parts.len(), self.duration()) {
+                                w.write_str(marker)?;
+                            }
+                        }
+                        for (i, part) in parts.iter().enumerate() {
+                            if i > 0 {
+                                w.write_char(' ')?;
+                            }
+                            w.write_str(part)?;
+                        }
+                    }
+                }
+                w.write_str(suffix)?;
+            }
+            /* Neither passed, nor due. */
+            None => w.write_str(&options.zero_text)?,
+        }
+        if let Some(label) = &options.reference_label {
+            write!(w, " (relative to {})", label)?;
+        }
+        Ok(())
+    }
+
+    /** Display the `cache`, used by the `Display` impls. */
+    fn fmt_breakdown(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::new();
+        self.write_breakdown(&mut output, f.precision())?;
+
+        /*
+        Can't use `f.pad` because it treats precision as a maximum number of chars, so we pad
+        ourselves. Left aligned by default, same as `str`.
+        */
+        let len = output.chars().count();
+        let padding = f.width().unwrap_or(0).saturating_sub(len);
+        let (before, after) = match f.align() {
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (0, padding),
+        };
+        for _ in 0..before {
+            f.write_char(f.fill())?;
+        }
+        f.write_str(&output)?;
+        for _ in 0..after {
+            f.write_char(f.fill())?;
+        }
+        Ok(())
+    }
+}
+
+/**
+A lighter `Elapsed` for when all you have is a `Duration`; no `DateTime`s are stored. Processed and
+displayed exactly like `Elapsed`, see `Breakdown`.
+*/
+#[derive(Debug, Clone)]
+pub struct DurationElapsed {
+    /** Difference in time, see `Elapsed::duration`. */
+    pub duration: Duration,
+    /** Whether the difference in time is in the past, see `Elapsed::passed`. */
+    pub passed: bool,
+    /** See `Elapsed::cache`. */
+    pub cache: Cache,
+    options: Options,
+}
+
+impl DurationElapsed {
+    /** Construct a new object then immediately process it. */
+    pub fn new(duration: Duration) -> Self {
+        Self::with_options(duration, Options::default())
+    }
+
+    /** Construct a new object with custom `Options` then immediately process it. */
+    pub fn with_options(duration: Duration, options: Options) -> Self {
+        let mut obj = Self {
+            duration,
+            passed: duration < Duration::zero(),
+            cache: Cache::default(),
+            options,
+        };
+        obj.process();
+        obj
+    }
+}
+
+impl Display for DurationElapsed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_breakdown(f)
+    }
+}
+
+impl Breakdown for DurationElapsed {
+    fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    fn passed(&self) -> bool {
+        self.passed
+    }
+
+    fn options(&self) -> &Options {
+        &self.options
+    }
+
+    fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    fn cache_mut(&mut self) -> &mut Cache {
+        &mut self.cache
+    }
+}
+
+/**
+UTC dates that ended with a leap second, `23:59:60`, used by `total_seconds_with_leaps`. None have
+been announced since 2016.
+*/
+const LEAP_SECONDS: [(i32, u32, u32); 27] = [
+    (1972, 6, 30),
+    (1972, 12, 31),
+    (1973, 12, 31),
+    (1974, 12, 31),
+    (1975, 12, 31),
+    (1976, 12, 31),
+    (1977, 12, 31),
+    (1978, 12, 31),
+    (1979, 12, 31),
+    (1981, 6, 30),
+    (1982, 6, 30),
+    (1983, 6, 30),
+    (1985, 6, 30),
+    (1987, 12, 31),
+    (1989, 12, 31),
+    (1990, 12, 31),
+    (1992, 6, 30),
+    (1993, 6, 30),
+    (1994, 6, 30),
+    (1995, 12, 31),
+    (1997, 6, 30),
+    (1998, 12, 31),
+    (2005, 12, 31),
+    (2008, 12, 31),
+    (2012, 6, 30),
+    (2015, 6, 30),
+    (2016, 12, 31),
+];
+
+/** How a bucket of time is phrased by `humanize`. */
+enum Phrase {
+    Fixed(&'static str),
+    /** Rounded count of a `TimeFrame`, e.g. `3 days`. */
+    Count(TimeFrame),
+}
+
+/**
+Buckets used by `humanize`, keyed on the absolute difference in time in seconds; the first bucket
+the difference falls below is used. Past or future is applied afterwards, so `in about an hour` and
+`about an hour ago` are always treated the same.
+*/
+const HUMANIZE_BUCKETS: [(u64, Phrase); 11] = [
+    (45, Phrase::Fixed("a few seconds")),
+    (90, Phrase::Fixed("a minute")),
+    (45 * 60, Phrase::Count(TimeFrame::Minute)),
+    (90 * 60, Phrase::Fixed("about an hour")),
+    (22 * 60 * 60, Phrase::Count(TimeFrame::Hour)),
+    (36 * 60 * 60, Phrase::Fixed("a day")),
+    (26 * 24 * 60 * 60, Phrase::Count(TimeFrame::Day)),
+    (45 * 24 * 60 * 60, Phrase::Fixed("about a month")),
+    (320 * 24 * 60 * 60, Phrase::Count(TimeFrame::Month)),
+    (548 * 24 * 60 * 60, Phrase::Fixed("about a year")),
+    (u64::MAX, Phrase::Count(TimeFrame::Year)),
+];
+
+/** Differences in time below this many milliseconds are an instant, displayed as `zero_text`. */
+pub const INSTANT_MILLIS: u64 = 10;
+
+/** Recurring periods `periodicity` describes, and the word used for each. */
+const PERIODS: [(TimeFrame, &str); 5] = [
+    (TimeFrame::Hour, "hourly"),
+    (TimeFrame::Day, "daily"),
+    (TimeFrame::Week, "weekly"),
+    (TimeFrame::Month, "monthly"),
+    (TimeFrame::Year, "yearly"),
+];
+
+/** Helper fn to get a count of a `TimeFrame` in words, e.g. `1 day` or `3 days`. */
+fn pluralize(tf: TimeFrame, val: u64) -> String {
+    if val == 1 {
+        format!("{} {}", val, tf.name())
+    } else {
+        format!("{} {}s", val, tf.name())
+    }
+}
+
+/** Display the difference in time between `dt` and `now` in one call, see `Elapsed::new`. */
+pub fn elapsed_from_now(dt: DateTime<Local>) -> String {
+    Elapsed::new(dt).to_string()
+}
+
+/**
+Parse a displayed breakdown, e.g. `3y 2mo ago` or `in 4d 2hr`, back to a `Duration`. Past
+differences in time, `ago`, `-` or `↓`, are negative. Months and years use their average length.
+*/
+pub fn parse_elapsed(s: &str) -> Result<Duration, &'static str> {
+    let s = s.trim();
+    let (body, past) = if let Some(body) = s.strip_suffix("ago") {
+        (body, true)
+    } else if let Some(body) = s.strip_prefix('-').or_else(|| s.strip_prefix('↓')) {
+        (body, true)
+    } else {
+        let body = s.strip_prefix("in ").or_else(|| s.strip_prefix('+'));
+        (body.or_else(|| s.strip_prefix('↑')).unwrap_or(s), false)
+    };
+    if body.trim() == "now" {
+        return Ok(Duration::zero());
+    }
+
+    /* Split on number/letter transitions, so `3y2mo4d` and `3y 2mo 4d` are the same. */
+    let body: String = body.chars().filter(|c| !c.is_whitespace()).collect();
+    if body.is_empty() {
+        return Err("Empty elapsed string");
+    }
+    let mut rest = body.as_str();
+    let mut millis: i64 = 0;
+    while !rest.is_empty() {
+        let split = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or("Missing `TimeFrame` in elapsed string")?;
+        let (digits, tail) = rest.split_at(split);
+        /* Letters are taken greedily, so `min` and `mo` are never mistaken for `m`. */
+        let split = tail
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(split);
+        let val: i64 = digits
+            .parse()
+            .map_err(|_| "Invalid value in elapsed string")?;
+        let tf = TimeFrame::try_from(unit)?;
+        millis = val
+            .checked_mul(tf.millis() as i64)
+            .and_then(|val| millis.checked_add(val))
+            .ok_or("Elapsed string is out of range")?;
+        rest = tail;
+    }
+    Ok(Duration::milliseconds(if past { -millis } else { millis }))
+}
+
+/** Helper fn to escape a str for use in a JSON string, see `Elapsed::to_json`. */
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/** A named point in time, e.g. a launch, so we can display `Launch in 3d`. */
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub name: String,
+    pub at: DateTime<Local>,
+}
+
+impl Event {
+    pub fn new(name: impl Into<String>, at: DateTime<Local>) -> Self {
+        Self {
+            name: name.into(),
+            at,
+        }
+    }
+
+    /**
+    Construct an `Elapsed` until, or since, the event relative to `context` rather than `now`, so
+    it can be customised before `render`ing.
+    */
+    pub fn elapsed_with_context(&self, context: DateTime<Local>) -> Elapsed {
+        Elapsed::new_with_context(self.at, context)
+    }
+
+    /** Display `elapsed` prefixed by the event's name, e.g. `Launch in 3d 2hr`. */
+    pub fn render(&self, elapsed: &Elapsed) -> String {
+        format!("{} {}", self.name, elapsed)
+    }
+}
+
+/**
+A timer counting up from an `epoch`, e.g. one started via CLI. Each tick of the clock tells you
+whether a new `TimeFrame` boundary has been crossed, so you only need to redraw then.
+*/
+#[derive(Debug, Clone)]
+pub struct EpochTimer {
+    elapsed: Elapsed,
+}
+
+impl EpochTimer {
+    /** Construct a timer that started at `epoch`. */
+    pub fn new(epoch: DateTime<Local>) -> Self {
+        Self {
+            elapsed: Elapsed::new_with_context(epoch, epoch),
+        }
+    }
+
+    /** Construct a timer starting `now`. */
+    pub fn start() -> Self {
+        Self::new(Local::now())
+    }
+
+    /** Time elapsed since the `epoch`, as of the last tick. */
+    pub fn elapsed(&self) -> &Elapsed {
+        &self.elapsed
+    }
+
+    /** Tick the clock `now`, see `tick_at`. */
+    pub fn tick(&mut self) -> Option<TimeFrame> {
+        self.tick_at(Local::now())
+    }
+
+    /**
+    Tick the clock at `now`. Returns the largest `TimeFrame` whose boundary has been crossed since
+    the last tick, e.g. `Minute` going from 59 seconds to a minute, or `None` if the display
+    wouldn't have changed. Milliseconds aren't considered.
+    */
+    pub fn tick_at(&mut self, now: DateTime<Local>) -> Option<TimeFrame> {
+        let previous = self.elapsed.magnitude().num_milliseconds().unsigned_abs();
+        self.elapsed.set_datetime_context(now);
+        let current = self.elapsed.magnitude().num_milliseconds().unsigned_abs();
+        let mut crossed = None;
+        let mut tf = TimeFrame::Second;
+        loop {
+            if previous / tf.millis() != current / tf.millis() {
+                crossed = Some(tf);
+            }
+            match tf.larger() {
+                Some(larger) => tf = larger,
+                None => return crossed,
+            }
+        }
+    }
+}
+
+/**
+Implemented for types that can be turned into an `Elapsed`, relative to `now`, so you can write
+functions that accept anything "elapsed-able", e.g. `fn show<T: IntoElapsed>(t: T)`.
+*/
+pub trait IntoElapsed {
+    fn into_elapsed(self) -> Elapsed;
+}
+
+impl IntoElapsed for DateTime<Local> {
+    fn into_elapsed(self) -> Elapsed {
+        Elapsed::new(self)
+    }
+}
+
+impl IntoElapsed for DateTime<Utc> {
+    fn into_elapsed(self) -> Elapsed {
+        Elapsed::new_then_localize(self)
+    }
+}
+
+impl IntoElapsed for DateTime<FixedOffset> {
+    fn into_elapsed(self) -> Elapsed {
+        Elapsed::new(self.with_timezone(&Local))
+    }
+}
+
+impl IntoElapsed for Duration {
+    fn into_elapsed(self) -> Elapsed {
+        Elapsed::new_from_duration(self)
+    }
+}
+
+impl IntoElapsed for i64 {
+    /**
+    Treated as a Unix timestamp in seconds. Panics if out of range, see `Elapsed::try_from` or
+    `Elapsed::from_unix` if you need to handle that.
+    */
+    fn into_elapsed(self) -> Elapsed {
+        Elapsed::from_unix(self).expect("Unix timestamp out of range for `Elapsed`")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum TimeFrame {
+    /*
+    Tempted to leave millisecond out because by virtue this crate isn't dealing with micro and nano
+    seconds, but milliseconds are useful in the Unix world. A millisecond to us wouldn't ever be
+    more than 60 however.
+    */
+    MilliSecond = 0,
+    Second = 1,
+    Minute = 2,
+    Hour = 3,
+    Day = 4,
+    Week = 5,
+    Month = 6,
+    Year = 7,
+    // Decade ...
+}
+
+impl TimeFrame {
+    /** Full, singular, name of a `TimeFrame`, e.g. `month`. */
+    pub const fn name(&self) -> &'static str {
+        match self {
+            TimeFrame::MilliSecond => "millisecond",
+            TimeFrame::Second => "second",
+            TimeFrame::Minute => "minute",
+            TimeFrame::Hour => "hour",
+            TimeFrame::Day => "day",
+            TimeFrame::Week => "week",
+            TimeFrame::Month => "month",
+            TimeFrame::Year => "year",
+        }
+    }
+
+    /** Get `TimeFrame` by its position in the `cache`. Panics if out of bounds. */
+    fn from_index(i: usize) -> Self {
+        match i {
+            0 => TimeFrame::MilliSecond,
+            1 => TimeFrame::Second,
+            2 => TimeFrame::Minute,
+            3 => TimeFrame::Hour,
+            4 => TimeFrame::Day,
+            5 => TimeFrame::Week,
+            6 => TimeFrame::Month,
+            7 => TimeFrame::Year,
+            _ => panic!("No `TimeFrame` at position {} of the `cache`", i),
+        }
+    }
+
+    /** Get the next larger `TimeFrame`, e.g. `Hour` for `Minute`, `None` for `Year`. */
+    pub const fn larger(&self) -> Option<TimeFrame> {
+        match self {
+            TimeFrame::MilliSecond => Some(TimeFrame::Second),
+            TimeFrame::Second => Some(TimeFrame::Minute),
+            TimeFrame::Minute => Some(TimeFrame::Hour),
+            TimeFrame::Hour => Some(TimeFrame::Day),
+            TimeFrame::Day => Some(TimeFrame::Week),
+            TimeFrame::Week => Some(TimeFrame::Month),
+            TimeFrame::Month => Some(TimeFrame::Year),
+            TimeFrame::Year => None,
+        }
+    }
+
+    /** Get the next smaller `TimeFrame`, e.g. `Second` for `Minute`, `None` for `MilliSecond`. */
+    pub const fn smaller(&self) -> Option<TimeFrame> {
+        match self {
+            TimeFrame::MilliSecond => None,
+            TimeFrame::Second => Some(TimeFrame::MilliSecond),
+            TimeFrame::Minute => Some(TimeFrame::Second),
+            TimeFrame::Hour => Some(TimeFrame::Minute),
+            TimeFrame::Day => Some(TimeFrame::Hour),
+            TimeFrame::Week => Some(TimeFrame::Day),
+            TimeFrame::Month => Some(TimeFrame::Week),
+            TimeFrame::Year => Some(TimeFrame::Month),
+        }
+    }
+}
+
+impl TimeFrame {
+    /**
+    Number of milliseconds in a `TimeFrame`. Months and years use their average length, a twelfth of
+    365.25 days and 365.25 days respectively.
+    */
+    const fn millis(&self) -> u64 {
+        match self {
+            TimeFrame::MilliSecond => 1,
+            TimeFrame::Second => 1_000,
+            TimeFrame::Minute => 60_000,
+            TimeFrame::Hour => 3_600_000,
+            TimeFrame::Day => 86_400_000,
+            TimeFrame::Week => 604_800_000,
+            TimeFrame::Month => 2_629_800_000,
+            TimeFrame::Year => 31_557_600_000,
+        }
+    }
+
+    /**
+    Number of whole seconds in a `TimeFrame`, `0` for `MilliSecond`. Months and years use their
+    average length, see `millis`. Usable in `const` contexts.
+    */
+    pub const fn seconds_in(&self) -> u64 {
+        self.millis() / 1_000
+    }
+
+    /**
+    Abbreviate `TimeFrame` to reasonably short string. Same as `Abbreviate::abbrev`, but usable in
+    `const` contexts.
+    */
+    pub const fn abbrev(&self) -> &'static str {
+        match self {
+            TimeFrame::MilliSecond => "ms",
+            TimeFrame::Second => "sec",
+            TimeFrame::Minute => "min",
+            TimeFrame::Hour => "hr",
+            TimeFrame::Day => "d",
+            TimeFrame::Week => "w",
+            TimeFrame::Month => "mo",
+            TimeFrame::Year => "y",
+        }
+    }
+
+    /**
+    Abbreviate `TimeFrame` to a still sensibly short string. Same as `Abbreviate::abbrev_short`,
+    but usable in `const` contexts.
+    */
+    pub const fn abbrev_short(&self) -> &'static str {
+        match self {
+            TimeFrame::MilliSecond => "ms",
+            TimeFrame::Second => "s",
+            TimeFrame::Minute => "min",
+            TimeFrame::Hour => "h",
+            TimeFrame::Day => "d",
+            TimeFrame::Week => "w",
+            TimeFrame::Month => "mo",
+            TimeFrame::Year => "y",
+        }
+    }
+
+    /** Abbreviate `TimeFrame` with Unicode superscript glyphs, e.g. `ʸ` for `3ʸ`. */
+    pub const fn glyph(&self) -> &'static str {
+        match self {
+            TimeFrame::MilliSecond => "ᵐˢ",
+            TimeFrame::Second => "ˢ",
+            TimeFrame::Minute => "ᵐⁱⁿ",
+            TimeFrame::Hour => "ʰ",
+            TimeFrame::Day => "ᵈ",
+            TimeFrame::Week => "ʷ",
+            TimeFrame::Month => "ᵐᵒ",
+            TimeFrame::Year => "ʸ",
+        }
+    }
+}
+
+impl From<TimeFrame> for String {
+    /** Return `String` from `TimeFrame`. */
+    fn from(tf: TimeFrame) -> Self {
+        match tf {
+            TimeFrame::MilliSecond => String::from("millisecond(s)"),
+            TimeFrame::Second => String::from("second(s)"),
+            TimeFrame::Minute => String::from("minute(s)"),
+            TimeFrame::Hour => String::from("hour(s)"),
+            TimeFrame::Day => String::from("day(s)"),
+            TimeFrame::Week => String::from("week(s)"),
+            TimeFrame::Month => String::from("month(s)"),
+            TimeFrame::Year => String::from("year(s)"),
+        }
+    }
+}
+
+impl Display for TimeFrame {
+    /** Display the singular name, e.g. `hour`, see `TimeFrame::name`. */
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl TryFrom<&str> for TimeFrame {
+    type Error = &'static str;
+    /** Attempt to parse `str` to `TimeFrame`. */
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().trim() {
+            "millisecond" | "ms" => Ok(Self::MilliSecond),
+            "second" | "sec" | "s" => Ok(Self::Second),
+            "minute" | "min" => Ok(Self::Minute),
+            "hour" | "hr" | "h" => Ok(Self::Hour),
+            "day" | "d" => Ok(Self::Day),
+            "week" | "wk" | "w" => Ok(Self::Week),
+            /* `m` is a deprecated alias from before months were `mo`, it prefers months. */
+            "month" | "mon" | "mo" | "m" => Ok(Self::Month),
+            "year" | "yr" | "y" => Ok(Self::Year),
+            _ => Err("Invalid or ambiguous string for `elapsed::TimeFrame`"),
+        }
+    }
+}
+
+impl From<TimeFrame> for char {
+    /** Return `char` from `TimeFrame`. */
+    fn from(tf: TimeFrame) -> Self {
+        match tf {
+            TimeFrame::MilliSecond => 'm',
+            TimeFrame::Second => 's',
+            TimeFrame::Minute => 'm',
+            TimeFrame::Hour => 'h',
+            TimeFrame::Day => 'd',
+            TimeFrame::Week => 'w',
+            TimeFrame::Month => 'm',
+            TimeFrame::Year => 'y',
+        }
+    }
+}
+
+impl TryFrom<char> for TimeFrame {
+    type Error = &'static str;
+    /** Attempt to parse `char` to `TimeFrame`. clashes will fail (m for ms, min, month). */
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase() {
+            's' => Ok(Self::Second),
+            'h' => Ok(Self::Hour),
+            'd' => Ok(Self::Day),
+            'w' => Ok(Self::Week),
+            'y' => Ok(Self::Year),
+            _ => Err("Invalid or ambiguous char for `elapsed::TimeFrame`"),
+        }
+    }
+}
+
+pub trait Abbreviate {
+    fn abbrev(&self) -> &'static str;
+    fn abbrev_short(&self) -> &'static str;
+}
+
+impl Abbreviate for TimeFrame {
+    /** Abbreviate `TimeFrame` to reasonably short string. */
+    fn abbrev(&self) -> &'static str {
+        TimeFrame::abbrev(self)
+    }
+
+    /**
+    Abbreviate `TimeFrame` to a still sensibly short string, mostly just a char except when there
+    are clashes (ms, min, month).
+    */
+    fn abbrev_short(&self) -> &'static str {
+        TimeFrame::abbrev_short(self)
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+
+    /**
+    Helper fn to build a fixture from wall clock values, so calendar spans read the same in any
+    zone. The earlier time is taken when clocks go back.
+    */
+    fn local(y: i32, mo: u32, d: u32, h: u32, min: u32, sec: u32) -> DateTime<Local> {
+        let naive = NaiveDate::from_ymd_opt(y, mo, d)
+            .and_then(|date| date.and_hms_opt(h, min, sec))
+            .expect("fixture must be a valid date & time");
+        Local
+            .from_local_datetime(&naive)
+            .earliest()
+            .expect("fixture mustn't fall in a gap")
+    }
+
+    #[test]
+    fn print_elapsed_since_birth() {
+        let dt_str = "1993-10-30T04:20:00Z";
+        let past_dt = dt_str
+            .parse::<DateTime<Local>>()
+            .expect("failed to parse str as `DateTime<Local>`");
+        let elapsed = Elapsed::new(past_dt);
+        println!("{}", elapsed)
+    }
+
+    #[test]
+    fn print_elapsed_since_recent() {
+        let now = Local::now();
+        let recent_dt = now - Duration::minutes(20);
+        let elapsed = Elapsed::new(recent_dt);
+        println!("{}", elapsed)
+    }
+
+    #[test]
+    fn pad_units_shows_zero_minutes() {
+        let context = Local::now();
+        let mut elapsed = Elapsed::new_with_context(context + Duration::hours(2), context);
+        assert_eq!(elapsed.to_string(), "in 2hr");
+        elapsed.set_pad_units(2);
+        assert_eq!(elapsed.to_string(), "in 2hr 0min");
+    }
+
+    #[test]
+    fn from_unix_timestamps() {
+        let past = Elapsed::from_unix(0).expect("epoch is a valid timestamp");
+        assert!(past.passed);
+        assert!(past.to_string().ends_with(" ago"));
+
+        let tomorrow = Local::now() + Duration::days(1) + Duration::minutes(1);
+        let future = Elapsed::from_unix_millis(tomorrow.timestamp_millis())
+            .expect("tomorrow is a valid timestamp");
+        assert!(!future.passed);
+        assert_eq!(future.to_string(), "in 1d");
+
+        assert_eq!(
+            Elapsed::from_unix(i64::MAX).unwrap_err(),
+            ElapsedError::TimestampOutOfRange
+        );
+        assert_eq!(
+            Elapsed::from_unix_millis(i64::MIN).unwrap_err(),
+            ElapsedError::TimestampOutOfRange
+        );
+    }
+
+    #[test]
+    fn into_elapsed_generic() {
+        fn show<T: IntoElapsed>(t: T) -> String {
+            t.into_elapsed().to_string()
+        }
+
+        assert_eq!(show(Duration::days(3) + Duration::minutes(1)), "in 3d");
+        assert!(show(0i64).ends_with(" ago"));
+        assert_eq!(show(0i64), Elapsed::try_from(0i64).unwrap().to_string());
+        assert_eq!(
+            Elapsed::try_from(i64::MAX).unwrap_err(),
+            ElapsedError::TimestampOutOfRange
+        );
+    }
+
+    #[test]
+    fn sign_modes() {
+        let context = Local::now();
+        let mut future = Elapsed::new_with_context(context + Duration::days(2), context);
+        let mut past = Elapsed::new_with_context(context - Duration::days(2), context);
+        assert_eq!(future.to_string(), "in 2d");
+        assert_eq!(past.to_string(), "2d ago");
+
+        future.set_sign_mode(SignMode::Symbol);
+        past.set_sign_mode(SignMode::Symbol);
+        assert_eq!(future.to_string(), "+2d");
+        assert_eq!(past.to_string(), "-2d");
+
+        future.set_sign_mode(SignMode::Arrow);
+        past.set_sign_mode(SignMode::Arrow);
+        assert_eq!(future.to_string(), "↑2d");
+        assert_eq!(past.to_string(), "↓2d");
+    }
+
+    #[test]
+    fn age_is_calendar_accurate() {
+        let today = Local::now().naive_local().date();
+        let anniversary = today
+            .with_year(today.year() - 30)
+            .unwrap_or_else(|| chrono::NaiveDate::from_ymd(today.year() - 30, 2, 28));
+        let birthdate =
+            |date: chrono::NaiveDate| Local.from_local_datetime(&date.and_hms(12, 0, 0)).unwrap();
+
+        let (years, _) = Elapsed::age(birthdate(anniversary.succ()));
+        assert_eq!(years, 29);
+        let (years, breakdown) = Elapsed::age(birthdate(anniversary.pred()));
+        assert_eq!(years, 30);
+        assert_eq!(breakdown, "30y 0mo");
+    }
+
+    #[test]
+    fn to_named_event() {
+        let launch = Event::new(
+            "Launch",
+            Local::now() + Duration::days(3) + Duration::hours(2),
+        );
+        assert_eq!(Elapsed::to_event(&launch), "Launch in 3d");
+
+        let context = local(2024, 5, 17, 9, 0, 0);
+        let launch = Event::new("Launch", local(2024, 5, 20, 11, 0, 0));
+        let mut elapsed = launch.elapsed_with_context(context);
+        assert_eq!(launch.render(&elapsed), "Launch in 3d");
+        elapsed.process_fixed(&[TimeFrame::Day, TimeFrame::Hour]);
+        assert_eq!(launch.render(&elapsed), "Launch in 3d 2hr");
+        let landing = launch.elapsed_with_context(local(2024, 5, 23, 13, 30, 0));
+        assert_eq!(launch.render(&landing), "Launch 3d ago");
+    }
+
+    #[test]
+    fn past_values_are_absolute() {
+        let context = Local::now();
+        let mut elapsed = Elapsed::custom_with_context(context - Duration::weeks(3), context);
+        assert_eq!(elapsed.weeks().1, 3);
+        assert_eq!(elapsed.days().1, 21);
+        assert_eq!(elapsed.hours().1, 21 * 24);
+        assert_eq!(elapsed.minutes().1, 21 * 24 * 60);
+        assert_eq!(elapsed.seconds().1, 21 * 24 * 60 * 60);
+    }
+
+    #[test]
+    fn digit_grouping() {
+        let context = Local::now();
+        let mut elapsed = Elapsed::custom_with_context(context + Duration::days(1), context);
+        assert_eq!(elapsed.seconds().0, "86400sec");
+        elapsed.set_digit_grouping(Some(','));
+        assert_eq!(elapsed.seconds().0, "86,400sec");
+        elapsed.cache_insert(TimeFrame::Second, 1_234_567);
+        assert_eq!(elapsed.to_string(), "in 1d 1,234,567sec");
+    }
+
+    #[test]
+    fn granularity_is_largest_time_frame() {
+        let context = Local::now();
+        let years = Elapsed::new_with_context(context - Duration::weeks(52 * 3), context);
+        assert_eq!(years.granularity(), Some(TimeFrame::Year));
+        let seconds = Elapsed::new_with_context(context + Duration::seconds(30), context);
+        assert_eq!(seconds.granularity(), Some(TimeFrame::Minute));
+        assert_eq!(Elapsed::custom(context).granularity(), None);
+    }
+
+    #[test]
+    fn from_system_time() {
+        let now = Elapsed::from_system_time(SystemTime::now());
+        assert_eq!(now.duration.num_seconds(), 0);
+
+        let before_epoch = std::time::UNIX_EPOCH - std::time::Duration::from_secs(60 * 60 * 24);
+        let elapsed = Elapsed::from_system_time(before_epoch);
+        assert!(elapsed.passed);
+        assert_eq!(elapsed.datetime.timestamp(), -60 * 60 * 24);
+    }
+
+    #[test]
+    fn formatter_precision_and_width() {
+        let context = Local::now();
+        let mut elapsed = Elapsed::custom_with_context(context + Duration::days(9), context);
+        elapsed.cache_insert(TimeFrame::Week, 1);
+        elapsed.cache_insert(TimeFrame::Day, 2);
+        elapsed.cache_insert(TimeFrame::Hour, 3);
+        assert_eq!(format!("{}", elapsed), "in 1w 2d 3hr");
+        assert_eq!(format!("{:.2}", elapsed), "in 1w 2d");
+        assert_eq!(format!("{:>10.1}", elapsed), "     in 1w");
+        assert_eq!(format!("{:-<8.1}", elapsed), "in 1w---");
+        assert_eq!(format!("{:^9.1}", elapsed), "  in 1w  ");
+    }
+
+    #[test]
+    fn chaining_subtracts_larger_time_frames() {
+        let context = Local::now();
+        let diff =
+            Duration::weeks(2) + Duration::days(3) + Duration::hours(5) + Duration::minutes(10);
+        let mut elapsed = Elapsed::custom_with_context(context + diff, context);
+        /* Getters are totals, only chaining subtracts. */
+        let hours = elapsed.weeks_and().days_and().hours();
+        assert_eq!(hours.1, 17 * 24 + 5);
+        assert_eq!(elapsed.to_string(), "in 2w 3d");
+
+        elapsed.hours_and().minutes_and();
+        assert_eq!(elapsed.to_string(), "in 2w 3d 5hr 10min");
+        assert_eq!(elapsed.get(TimeFrame::Hour).map(|tuple| tuple.1), Some(5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn chaining_smallest_first_panics() {
+        let mut elapsed = Elapsed::custom(Local::now() + Duration::days(400));
+        elapsed.seconds_and().years_and();
+    }
+
+    #[test]
+    fn duration_elapsed_without_datetimes() {
+        assert_eq!(DurationElapsed::new(Duration::days(3)).to_string(), "in 3d");
+        assert_eq!(
+            DurationElapsed::new(Duration::weeks(-2)).to_string(),
+            "2w ago"
+        );
+        assert!(std::mem::size_of::<DurationElapsed>() < std::mem::size_of::<Elapsed>());
+    }
+
+    #[test]
+    fn replace_returns_previous() {
+        let context = Local::now();
+        let datetime = context + Duration::days(2);
+        let mut elapsed = Elapsed::new_with_context(datetime, context);
+
+        assert_eq!(
+            elapsed.replace_datetime(context + Duration::days(5)),
+            datetime
+        );
+        assert_eq!(elapsed.to_string(), "in 5d");
+        assert_eq!(
+            elapsed.replace_context(context + Duration::days(1)),
+            context
+        );
+        assert_eq!(elapsed.to_string(), "in 4d");
+    }
+
+    #[test]
+    fn zero_duration_is_now() {
+        let dt = Local::now();
+        let mut elapsed = Elapsed::new_with_context(dt, dt);
+        assert!(!elapsed.passed);
+        assert_eq!(elapsed.to_string(), "now");
+        elapsed.set_zero_text("just now");
+        assert_eq!(elapsed.to_string(), "just now");
+        assert_eq!(DurationElapsed::new(Duration::zero()).to_string(), "now");
+    }
+
+    #[test]
+    fn total_days_as_f64() {
+        let context = Local::now();
+        let future = Elapsed::custom_with_context(context + Duration::hours(36), context);
+        assert_eq!(future.total_days_f64(), 1.5);
+        let past = Elapsed::custom_with_context(context - Duration::hours(6), context);
+        assert_eq!(past.total_days_f64(), -0.25);
+    }
+
+    #[test]
+    fn describe_range_with_dates() {
+        let context = local(2024, 1, 1, 0, 0, 0);
+        let datetime = local(2024, 1, 15, 0, 0, 0);
+        let elapsed = Elapsed::new_with_context(datetime, context);
+        assert_eq!(
+            elapsed.describe_range("%Y-%m-%d"),
+            "between 2024-01-01 and 2024-01-15 (2w)"
+        );
+    }
+
+    #[test]
+    fn describe_range_respects_frame_order() {
+        let context = local(2024, 1, 1, 0, 0, 0);
+        let datetime = local(2024, 1, 15, 0, 0, 0);
+        let mut elapsed = Elapsed::new_with_context(datetime, context);
+        elapsed.process_fixed(&[TimeFrame::Week, TimeFrame::Day]);
+        assert_eq!(
+            elapsed.describe_range("%Y-%m-%d"),
+            "between 2024-01-01 and 2024-01-15 (2w 0d)"
+        );
+        elapsed
+            .set_trim_leading_zeros(true)
+            .set_frame_order(vec![TimeFrame::Day, TimeFrame::Week]);
+        assert_eq!(
+            elapsed.describe_range("%Y-%m-%d"),
+            "between 2024-01-01 and 2024-01-15 (2w)"
+        );
+    }
+
+    #[test]
+    fn nearest_and_farthest() {
+        let context = Local::now();
+        let events: Vec<Elapsed> = [-Duration::days(3), Duration::hours(5), Duration::weeks(2)]
+            .iter()
+            .map(|diff| Elapsed::new_with_context(context + *diff, context))
+            .collect();
+
+        assert_eq!(Elapsed::nearest(&events).unwrap().to_string(), "in 5hr");
+        assert_eq!(Elapsed::farthest(&events).unwrap().to_string(), "in 2w");
+        assert!(Elapsed::nearest(&[]).is_none());
+    }
+
+    #[test]
+    fn smart_relative_or_date() {
+        let context = local(2024, 7, 1, 12, 0, 0);
+        let recent = Elapsed::new_with_context(context - Duration::minutes(5), context);
+        assert_eq!(recent.smart(), "5min ago");
+
+        let mut older = Elapsed::new_with_context(local(2024, 1, 5, 9, 0, 0), context);
+        assert_eq!(older.smart(), "on Jan 5, 2024");
+        older.set_smart_cutoff(Duration::days(365));
+        assert_eq!(older.smart(), older.to_string());
+        older
+            .set_smart_cutoff(Duration::days(1))
+            .set_smart_format("%Y-%m-%d");
+        assert_eq!(older.smart(), "2024-01-05");
+    }
+
+    #[test]
+    fn get_cache_entries() {
+        let context = Local::now();
+        let elapsed = Elapsed::new_with_context(context + Duration::days(3), context);
+        let days = elapsed
+            .get(TimeFrame::Day)
+            .expect("days should be populated");
+        assert_eq!(days.0, "3d");
+        assert_eq!(days.1, 3);
+        assert!(elapsed.get(TimeFrame::Year).is_none());
+    }
+
+    #[test]
+    fn write_to_writer() {
+        let context = Local::now();
+        let mut buffer: Vec<u8> = Vec::new();
+        for diff in &[Duration::days(3), -Duration::weeks(2), Duration::hours(5)] {
+            let elapsed = Elapsed::new_with_context(context + *diff, context);
+            elapsed.write_to(&mut buffer).unwrap();
+            buffer.push(b'\n');
+        }
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            "in 3d\n2w ago\nin 5hr\n"
+        );
+    }
+
+    #[test]
+    fn remaining_days_are_kept() {
+        let context = local(2024, 1, 1, 12, 0, 0);
+        let datetime = local(2024, 2, 11, 12, 0, 0);
+        let elapsed = Elapsed::new_with_context(datetime, context);
+        assert_eq!(elapsed.to_string(), "in 1mo 1w 3d");
+        let context = Local::now();
+        let elapsed = Elapsed::new_with_context(context - Duration::days(10), context);
+        assert_eq!(elapsed.to_string(), "1w 3d ago");
+    }
+
+    #[test]
+    fn add_and_sub_duration() {
+        let context = Local::now();
+        let elapsed = Elapsed::new_with_context(context + Duration::hours(2), context);
+        assert_eq!(elapsed.to_string(), "in 2hr");
+        let later = elapsed.clone() + Duration::hours(1);
+        assert_eq!(later.to_string(), "in 3hr");
+        let earlier = elapsed - Duration::hours(3);
+        assert_eq!(earlier.to_string(), "1hr ago");
+    }
+
+    #[test]
+    fn legend_of_time_frames() {
+        let context = local(2024, 1, 1, 12, 0, 0);
+        let datetime = local(2024, 2, 11, 12, 0, 0);
+        let elapsed = Elapsed::new_with_context(datetime, context);
+        assert_eq!(
+            elapsed.legend(),
+            vec![
+                (TimeFrame::Month, "mo", "month"),
+                (TimeFrame::Week, "w", "week"),
+                (TimeFrame::Day, "d", "day"),
+            ]
+        );
+    }
+
+    #[test]
+    fn humanize_is_symmetric() {
+        let context = Local::now();
+        let future = Elapsed::new_with_context(context + Duration::minutes(59), context);
+        let past = Elapsed::new_with_context(context - Duration::minutes(59), context);
+        assert_eq!(future.humanize(), "in about an hour");
+        assert_eq!(past.humanize(), "about an hour ago");
+
+        let days = Elapsed::new_with_context(context - Duration::days(3), context);
+        assert_eq!(days.humanize(), "3 days ago");
+        let years = Elapsed::new_with_context(context + Duration::weeks(52 * 3), context);
+        assert_eq!(years.humanize(), "in 3 years");
+    }
+
+    #[test]
+    fn cached_values() {
+        let context = Local::now();
+        let elapsed = Elapsed::new_with_context(context - Duration::days(5), context);
+        assert_eq!(elapsed.cached_value(TimeFrame::Day), Some(5));
+        assert_eq!(elapsed.cached_value(TimeFrame::Hour), None);
+    }
+
+    #[test]
+    fn process_fixed_frames() {
+        let context = Local::now();
+        let diff = Duration::weeks(3) + Duration::hours(5) + Duration::minutes(6);
+        let mut elapsed = Elapsed::new_with_context(context + diff, context);
+        assert_eq!(elapsed.to_string(), "in 3w");
+
+        elapsed.process_fixed(&[TimeFrame::Minute, TimeFrame::Day, TimeFrame::Hour]);
+        assert_eq!(elapsed.to_string(), "in 21d 5hr 6min");
+        assert!(elapsed.cached_value(TimeFrame::Day).unwrap() >= 14);
+    }
+
+    #[test]
+    fn as_str_borrows_from_cache() {
+        let context = Local::now();
+        let elapsed = Elapsed::new_with_context(context + Duration::days(3), context);
+        let borrowed = elapsed.as_str().expect("a single time frame is populated");
+        assert_eq!(borrowed, "3d");
+        let cached: &str = &elapsed.get(TimeFrame::Day).unwrap().0;
+        assert!(std::ptr::eq(borrowed, cached));
+
+        let elapsed = Elapsed::new_with_context(context + Duration::days(10), context);
+        assert_eq!(elapsed.as_str(), None);
+    }
+
+    #[test]
+    fn show_millis_with_seconds() {
+        let context = Local::now();
+        let diff = Duration::milliseconds(1500);
+        let mut elapsed = Elapsed::new_with_context(context + diff, context);
+        assert_eq!(elapsed.to_string(), "in 0min 1sec");
+        elapsed.set_show_millis(true);
+        assert_eq!(elapsed.to_string(), "in 0min 1sec 500ms");
+    }
+
+    #[test]
+    fn const_time_frame_metadata() {
+        const ABBREVIATIONS: [&str; 3] = [
+            TimeFrame::Year.abbrev(),
+            TimeFrame::Hour.abbrev_short(),
+            TimeFrame::Minute.name(),
+        ];
+        const SECONDS_IN_DAY: u64 = TimeFrame::Day.seconds_in();
+        assert_eq!(ABBREVIATIONS, ["y", "h", "minute"]);
+        assert_eq!(SECONDS_IN_DAY, 86_400);
+        assert_eq!(TimeFrame::Year.seconds_in(), 31_557_600);
+        assert_eq!(TimeFrame::MilliSecond.seconds_in(), 0);
+        assert_eq!(Abbreviate::abbrev(&TimeFrame::Second), "sec");
+    }
+
+    #[test]
+    fn subsec_nanos_and_fractional_seconds() {
+        let context = Local::now();
+        let diff = Duration::milliseconds(1500);
+        let mut elapsed = Elapsed::new_with_context(context + diff, context);
+        assert_eq!(elapsed.subsec_nanos(), 500_000_000);
+        elapsed.set_subsec_digits(Some(6));
+        assert_eq!(elapsed.to_string(), "in 0min 1.500000sec");
+
+        let past = Elapsed::new_with_context(context - diff, context);
+        assert_eq!(past.subsec_nanos(), 500_000_000);
+    }
+
+    #[test]
+    fn reset_undoes_chaining() {
+        let context = local(2020, 1, 1, 0, 0, 0);
+        let datetime = local(2023, 3, 15, 6, 30, 0);
+        let mut elapsed = Elapsed::custom_with_context(datetime, context);
+        elapsed.years_and().seconds();
+        elapsed.duration = Duration::zero();
+        elapsed.reset();
+
+        let fresh = Elapsed::new_with_context(datetime, context);
+        assert_eq!(elapsed.to_string(), fresh.to_string());
+        assert_eq!(elapsed.duration, fresh.duration);
+    }
+
+    #[test]
+    fn process_exact_boundaries() {
+        let context = Local::now();
+        let boundaries = [
+            (Duration::seconds(1), "0min 1sec"),
+            (Duration::minutes(5), "5min"),
+            (Duration::minutes(60), "1hr"),
+            (Duration::hours(4), "4hr"),
+            (Duration::hours(24), "1d"),
+            (Duration::hours(168), "1w"),
+        ];
+        for (diff, expected) in boundaries.iter() {
+            let elapsed = Elapsed::new_with_context(context + *diff, context);
+            assert_eq!(
+                elapsed.to_string(),
+                format!("in {}", expected),
+                "{:?}",
+                diff
+            );
+        }
+
+        /* Calendar months & years, or 4 weeks a month without a calendar. */
+        let context = local(2023, 1, 1, 12, 0, 0);
+        let month = Elapsed::new_with_context(local(2023, 2, 1, 12, 0, 0), context);
+        assert_eq!(month.to_string(), "in 1mo");
+        let year = Elapsed::new_with_context(local(2024, 1, 1, 12, 0, 0), context);
+        assert_eq!(year.to_string(), "in 1y");
+        assert_eq!(
+            DurationElapsed::new(Duration::days(28)).to_string(),
+            "in 1mo"
+        );
+        assert_eq!(
+            DurationElapsed::new(Duration::days(336)).to_string(),
+            "in 1y"
+        );
+    }
+
+    #[test]
+    fn process_counts_calendar_months() {
+        let context = local(2021, 6, 1, 9, 0, 0);
+        let datetime = local(2022, 6, 1, 9, 0, 0);
+        let elapsed = Elapsed::new_with_context(datetime, context);
+        assert_eq!(elapsed.to_string(), "in 1y");
+        assert_eq!(elapsed.num_years(), 1);
+
+        let datetime = local(2021, 6, 29, 9, 0, 0);
+        let elapsed = Elapsed::new_with_context(datetime, context);
+        assert_eq!(elapsed.to_string(), "in 4w");
+
+        /* Clamped to Feb 28th, then 30 days on. */
+        let context = local(2023, 1, 31, 9, 0, 0);
+        let elapsed = Elapsed::new_with_context(local(2023, 3, 30, 9, 0, 0), context);
+        assert_eq!(elapsed.to_string(), "in 1mo 4w 2d");
+    }
+
+    #[test]
+    fn to_json_matches_display() {
+        let context = local(2020, 1, 1, 0, 0, 0);
+        let mut elapsed = Elapsed::new_with_context(context - Duration::weeks(170), context);
+        let json = elapsed.to_json();
+        assert!(json.starts_with("{\"passed\":true,\"years\":3,\"months\":3,"));
+        let display = json
+            .split("\"display\":\"")
+            .nth(1)
+            .and_then(|rest| rest.strip_suffix("\"}"))
+            .expect("`display` is the last field");
+        assert_eq!(display, elapsed.to_string());
+
+        elapsed.set_zero_text("\"now\"").set_datetime(context);
+        assert!(elapsed.to_json().ends_with("\"display\":\"\\\"now\\\"\"}"));
+    }
+
+    #[test]
+    fn over_mode_keeps_largest_frame() {
+        let context = local(2024, 6, 1, 12, 0, 0);
+        let datetime = local(2023, 1, 1, 12, 0, 0);
+        let mut elapsed = Elapsed::new_with_context(datetime, context);
+        assert_eq!(elapsed.to_string(), "1y 5mo ago");
+        elapsed.set_over_mode(Some(TimeFrame::Year));
+        assert_eq!(elapsed.to_string(), "over 1 year ago");
+
+        let datetime = local(2024, 1, 1, 12, 0, 0);
+        let mut elapsed = Elapsed::new_with_context(datetime, context);
+        elapsed.set_over_mode(Some(TimeFrame::Year));
+        assert_eq!(elapsed.to_string(), "5mo ago");
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test(start_paused = true)]
+    async fn countdown_stream_ticks() {
+        use futures_util::StreamExt;
+
+        let target = Local::now() + Duration::hours(1);
+        let start = tokio::time::Instant::now();
+        let ticks: Vec<Elapsed> = Elapsed::countdown_stream(target, Duration::seconds(30))
+            .take(3)
+            .collect()
+            .await;
+        assert_eq!(ticks.len(), 3);
+        assert!(ticks.iter().all(|elapsed| !elapsed.passed));
+        assert_eq!(start.elapsed(), std::time::Duration::from_secs(60));
+
+        let target = Local::now() - Duration::hours(1);
+        let ticks = Elapsed::countdown_stream(target, Duration::seconds(30))
+            .count()
+            .await;
+        assert_eq!(ticks, 1);
+    }
+
+    #[test]
+    fn fixed_remainders_stay_within_natural_range() {
+        let context = local(2020, 2, 29, 13, 45, 10);
+        let frames = [
+            TimeFrame::Year,
+            TimeFrame::Month,
+            TimeFrame::Week,
+            TimeFrame::Day,
+            TimeFrame::Hour,
+            TimeFrame::Minute,
+            TimeFrame::Second,
+        ];
+        let limits = [
+            (TimeFrame::Month, 12),
+            (TimeFrame::Week, 5),
+            (TimeFrame::Day, 7),
+            (TimeFrame::Hour, 24),
+            (TimeFrame::Minute, 60),
+            (TimeFrame::Second, 60),
+        ];
+
+        /* Deterministic pseudo random durations, in both directions, of up to ~30 years. */
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        for i in 0..500 {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let secs = (seed % 950_000_000) as i64;
+            let diff = if i % 2 == 0 {
+                Duration::seconds(secs)
+            } else {
+                -Duration::seconds(secs)
+            };
+            let mut elapsed = Elapsed::custom_with_context(context + diff, context);
+
+            elapsed.process_fixed(&frames);
+            for (tf, limit) in limits.iter() {
+                let val = elapsed.cached_value(*tf).unwrap();
+                assert!(val < *limit, "{:?} {} out of range for {:?}", tf, val, diff);
+            }
+
+            elapsed.process_fixed(&[TimeFrame::Day, TimeFrame::Hour]);
+            assert!(elapsed.cached_value(TimeFrame::Hour).unwrap() < 24);
+            elapsed.clear_cache();
+            elapsed.years_and().months_and();
+            assert!(elapsed.cached_value(TimeFrame::Month).unwrap() < 12);
+        }
+    }
+
+    #[test]
+    fn tokens_match_display_body() {
+        let context = local(2024, 2, 11, 12, 0, 0);
+        let datetime = local(2024, 1, 1, 12, 0, 0);
+        let elapsed = Elapsed::new_with_context(datetime, context);
+        let tokens = elapsed.tokens();
+        assert_eq!(tokens, ["1mo", "1w", "3d"]);
+        assert_eq!(format!("{} ago", tokens.join(" ")), elapsed.to_string());
+    }
+
+    #[test]
+    fn batch_against_contexts() {
+        let target = local(2024, 6, 1, 12, 0, 0);
+        let contexts = [
+            target - Duration::days(3),
+            target,
+            target + Duration::hours(5),
+        ];
+        let rendered = Elapsed::batch_against(target, &contexts);
+        assert_eq!(rendered, ["in 3d", "now", "5hr ago"]);
+        assert!(Elapsed::batch_against(target, &[]).is_empty());
+    }
+
+    #[test]
+    fn direction_is_tri_state() {
+        let context = Local::now();
+        let past = Elapsed::new_with_context(context - Duration::seconds(1), context);
+        let now = Elapsed::new_with_context(context, context);
+        let future = Elapsed::new_with_context(context + Duration::seconds(1), context);
+        assert_eq!(past.direction(), Direction::Past);
+        assert_eq!(now.direction(), Direction::Now);
+        assert!(!now.passed);
+        assert_eq!(future.direction(), Direction::Future);
+        assert_eq!(
+            DurationElapsed::new(Duration::zero()).direction(),
+            Direction::Now
+        );
+
+        /* Precise, however tiny the difference. */
+        let tiny = Elapsed::new_with_context(context + Duration::nanoseconds(1), context);
+        assert_eq!(tiny.direction(), Direction::Future);
+        let tiny = Elapsed::new_with_context(context - Duration::milliseconds(2), context);
+        assert_eq!(tiny.direction(), Direction::Past);
+    }
+
+    #[test]
+    fn time_frame_navigation() {
+        let mut steps = vec![TimeFrame::Second];
+        while let Some(tf) = steps.last().unwrap().larger() {
+            steps.push(tf);
+        }
+        assert_eq!(
+            steps,
+            [
+                TimeFrame::Second,
+                TimeFrame::Minute,
+                TimeFrame::Hour,
+                TimeFrame::Day,
+                TimeFrame::Week,
+                TimeFrame::Month,
+                TimeFrame::Year,
+            ]
+        );
+        assert_eq!(TimeFrame::Year.smaller(), Some(TimeFrame::Month));
+        assert_eq!(TimeFrame::MilliSecond.smaller(), None);
+        assert_eq!(TimeFrame::MilliSecond.larger(), Some(TimeFrame::Second));
+    }
+
+    #[test]
+    fn calendar_days_crossing_midnight() {
+        let context = local(2024, 3, 9, 23, 0, 0);
+        let tomorrow = local(2024, 3, 10, 1, 0, 0);
+        let elapsed = Elapsed::new_with_context(tomorrow, context);
+        assert_eq!(elapsed.duration.num_days(), 0);
+        assert_eq!(elapsed.calendar_days(), 1);
+
+        let elapsed = Elapsed::new_with_context(context, tomorrow);
+        assert_eq!(elapsed.calendar_days(), -1);
+    }
+
+    #[test]
+    fn custom_frame_order() {
+        let context = Local::now();
+        let mut elapsed = Elapsed::custom_with_context(context - Duration::seconds(3723), context);
+        elapsed.process_fixed(&[TimeFrame::Hour, TimeFrame::Minute, TimeFrame::Second]);
+        assert_eq!(elapsed.to_string(), "1hr 2min 3sec ago");
+        elapsed.set_frame_order(vec![
+            TimeFrame::Second,
+            TimeFrame::Minute,
+            TimeFrame::Day,
+            TimeFrame::Hour,
+        ]);
+        assert_eq!(elapsed.to_string(), "3sec 2min 1hr ago");
+        assert_eq!(elapsed.tokens(), ["3sec", "2min", "1hr"]);
+    }
+
+    #[test]
+    fn num_years_is_calendar_based() {
+        let context = local(2021, 6, 1, 9, 0, 0);
+        let mut elapsed = Elapsed::custom_with_context(local(2022, 6, 1, 9, 0, 0), context);
+        assert_eq!(elapsed.num_years(), 1);
+        assert_eq!(elapsed.years().0, "1y");
+        elapsed.process();
+        assert_eq!(elapsed.to_string(), "in 1y");
+
+        let elapsed = Elapsed::custom_with_context(local(2020, 6, 1, 9, 0, 0), context);
+        assert_eq!(elapsed.num_years(), 1);
+        let elapsed = Elapsed::custom_with_context(local(2022, 5, 31, 9, 0, 0), context);
+        assert_eq!(elapsed.num_years(), 0);
+    }
+
+    #[test]
+    fn describe_as_sentence() {
+        let context = Local::now();
+        let diff = Duration::days(3) + Duration::hours(2) + Duration::minutes(30);
+        let mut elapsed = Elapsed::custom_with_context(context + diff, context);
+        elapsed.process_fixed(&[TimeFrame::Day, TimeFrame::Hour, TimeFrame::Minute]);
+        assert_eq!(
+            elapsed.describe(),
+            "This event is due in 3 days, 2 hours and 30 minutes."
+        );
+
+        let mut elapsed = Elapsed::new_with_context(context - Duration::weeks(1), context);
+        elapsed.set_describe_prefix("The launch");
+        assert_eq!(elapsed.describe(), "The launch happened 1 week ago.");
+        elapsed.set_datetime(context);
+        assert_eq!(elapsed.describe(), "The launch is due now.");
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn from_time_offset_matches_chrono() {
+        let offset = time::UtcOffset::from_hms(5, 30, 0).unwrap();
+        let at = time::OffsetDateTime::from_unix_timestamp_nanos(1_000_000_000_123_000_000)
+            .unwrap()
+            .to_offset(offset);
+        let elapsed = Elapsed::from_time_offset(at);
+        let chrono_built = Elapsed::new_then_localize(Utc.timestamp(1_000_000_000, 123_000_000));
+        assert_eq!(elapsed.datetime, chrono_built.datetime);
+        assert_eq!(elapsed.to_string(), chrono_built.to_string());
+        assert_eq!(Elapsed::from(at).datetime, chrono_built.datetime);
+    }
+
+    #[test]
+    fn time_until_display_changes() {
+        let context = Local::now();
+        let diff = Duration::minutes(7) + Duration::seconds(30);
+        let future = Elapsed::new_with_context(context + diff, context);
+        assert_eq!(future.to_string(), "in 7min");
+        assert_eq!(future.time_until_change(), Duration::seconds(30));
+
+        let diff = Duration::minutes(7) + Duration::seconds(20);
+        let past = Elapsed::new_with_context(context - diff, context);
+        assert_eq!(past.time_until_change(), Duration::seconds(40));
+
+        let future = Elapsed::new_with_context(context + Duration::minutes(7), context);
+        assert_eq!(future.to_string(), "in 7min");
+        assert_eq!(future.time_until_change(), Duration::minutes(1));
+    }
+
+    #[test]
+    fn anniversaries() {
+        let today = local(2024, 5, 17, 9, 0, 0);
+        let birthdate = local(1990, 5, 17, 23, 0, 0);
+        let elapsed = Elapsed::custom_with_context(birthdate, today);
+        assert!(elapsed.is_anniversary());
+        assert_eq!(elapsed.years_since_anniversary(), 34);
+
+        let elapsed = Elapsed::custom_with_context(birthdate, today - Duration::days(1));
+        assert!(!elapsed.is_anniversary());
+        assert_eq!(elapsed.years_since_anniversary(), 33);
+
+        let leap_day = local(2020, 2, 29, 12, 0, 0);
+        let context = local(2023, 2, 28, 12, 0, 0);
+        let mut elapsed = Elapsed::custom_with_context(leap_day, context);
+        assert!(elapsed.is_anniversary());
+        assert_eq!(elapsed.years_since_anniversary(), 3);
+        /* Calendar years share the leap day rule. */
+        assert_eq!(elapsed.num_years(), 3);
+        assert_eq!(elapsed.years().0, "3y");
+        assert_eq!(elapsed.total_months(), 36);
+
+        let elapsed = Elapsed::custom_with_context(leap_day, context - Duration::days(1));
+        assert!(!elapsed.is_anniversary());
+        assert_eq!(elapsed.years_since_anniversary(), 2);
+        assert_eq!(elapsed.num_years(), 2);
+    }
+
+    #[test]
+    fn month_abbreviation_round_trips() {
+        let context = local(2021, 1, 1, 0, 0, 0);
+        let months = Duration::milliseconds(3 * TimeFrame::Month.millis() as i64);
+        let datetime = local(2021, 4, 1, 0, 0, 0);
+        let mut elapsed = Elapsed::custom_with_context(datetime, context);
+        elapsed.process_fixed(&[TimeFrame::Month]);
+        assert_eq!(elapsed.to_string(), "in 3mo");
+        assert_eq!(parse_elapsed(&elapsed.to_string()), Ok(months));
+        assert_eq!(parse_elapsed("3mo ago"), Ok(-months));
+        assert_eq!(parse_elapsed("3m"), Ok(months));
+        assert_eq!(parse_elapsed("3min"), Ok(Duration::minutes(3)));
+        assert_eq!(parse_elapsed("1d 2hr"), Ok(Duration::hours(26)));
+        assert!(parse_elapsed("3").is_err());
+        assert!(parse_elapsed("3 fortnights").is_err());
+    }
+
+    #[test]
+    fn epoch_timer_ticks_across_minute() {
+        let epoch = local(2024, 1, 1, 12, 0, 0);
+        let mut timer = EpochTimer::new(epoch);
+        assert_eq!(timer.tick_at(epoch + Duration::milliseconds(500)), None);
+        assert_eq!(
+            timer.tick_at(epoch + Duration::seconds(58)),
+            Some(TimeFrame::Second)
+        );
+        assert_eq!(timer.tick_at(epoch + Duration::milliseconds(58_900)), None);
+        assert_eq!(
+            timer.tick_at(epoch + Duration::seconds(61)),
+            Some(TimeFrame::Minute)
+        );
+        assert_eq!(timer.elapsed().to_string(), "1min 1sec ago");
+    }
+
+    #[test]
+    fn glyph_abbreviations() {
+        let glyphs = [
+            (TimeFrame::MilliSecond, "5ᵐˢ"),
+            (TimeFrame::Second, "5ˢ"),
+            (TimeFrame::Minute, "5ᵐⁱⁿ"),
+            (TimeFrame::Hour, "5ʰ"),
+            (TimeFrame::Day, "5ᵈ"),
+            (TimeFrame::Week, "5ʷ"),
+            (TimeFrame::Month, "5ᵐᵒ"),
+            (TimeFrame::Year, "5ʸ"),
+        ];
+        let options = Options {
+            abbrev_style: AbbrevStyle::Glyph,
+            ..Options::default()
+        };
+        for (tf, expected) in glyphs.iter() {
+            assert_eq!(options.as_tuple(*tf, 5).0, *expected);
+        }
+
+        let context = local(2024, 6, 1, 12, 0, 0);
+        let datetime = local(2023, 1, 1, 12, 0, 0);
+        let mut elapsed = Elapsed::new_with_context(datetime, context);
+        elapsed.set_abbrev_style(AbbrevStyle::Glyph);
+        assert_eq!(elapsed.to_string(), "1ʸ 5ᵐᵒ ago");
+    }
+
+    #[test]
+    fn setters_preserve_options() {
+        let context = Local::now();
+        let mut elapsed = Elapsed::new_with_context(context - Duration::days(2), context);
+        elapsed
+            .set_sign_mode(SignMode::Symbol)
+            .set_abbrev_style(AbbrevStyle::Glyph);
+        elapsed.set_datetime(context + Duration::days(3));
+        assert_eq!(elapsed.to_string(), "+3ᵈ");
+        elapsed.set_datetime_context(context - Duration::days(1));
+        assert_eq!(elapsed.to_string(), "+4ᵈ");
+
+        elapsed.reconfigure();
+        assert_eq!(elapsed.to_string(), "in 4d");
+    }
+
+    #[test]
+    fn round_nearest_promotes_at_every_rollup() {
+        let context = Local::now();
+        let boundaries = [
+            (Duration::seconds(29), "in 0min 29sec"),
+            (Duration::seconds(89), "in 1min 29sec"),
+            (Duration::seconds(270), "in 5min"),
+            (Duration::minutes(29), "in 29min"),
+            (Duration::minutes(30), "in 1hr"),
+            (Duration::minutes(100), "in 2hr"),
+            (Duration::hours(11), "in 11hr"),
+            (Duration::hours(23), "in 1d"),
+            (Duration::hours(36), "in 2d"),
+            (Duration::days(10), "in 1w 3d"),
+        ];
+        for (diff, expected) in boundaries.iter() {
+            let mut elapsed = Elapsed::new_with_context(context + *diff, context);
+            elapsed.set_rounding(Rounding::RoundNearest);
+            assert_eq!(elapsed.to_string(), *expected, "{:?}", diff);
+        }
+
+        let mut elapsed = Elapsed::new_with_context(context - Duration::hours(23), context);
+        assert_eq!(elapsed.to_string(), "23hr ago");
+        elapsed.set_rounding(Rounding::RoundNearest);
+        assert_eq!(elapsed.to_string(), "1d ago");
+    }
+
+    #[test]
+    fn round_nearest_calendar_months() {
+        let context = local(2023, 1, 1, 12, 0, 0);
+        let calendar = [
+            /* 27d of February rounds to 4w, the whole month. */
+            (local(2023, 2, 28, 12, 0, 0), "in 1mo 3w 6d", "in 2mo"),
+            /* 24d of March rounds to 3w 3d, short of the month. */
+            (local(2023, 3, 25, 12, 0, 0), "in 2mo 3w 3d", "in 2mo 3w 3d"),
+            (local(2023, 6, 15, 12, 0, 0), "in 5mo 2w", "in 5mo 2w"),
+            /* 11 months rounds to a year. */
+            (local(2023, 12, 28, 12, 0, 0), "in 11mo 3w 6d", "in 1y"),
+        ];
+        for (datetime, floor, nearest) in calendar.iter() {
+            let mut elapsed = Elapsed::new_with_context(*datetime, context);
+            assert_eq!(elapsed.to_string(), *floor, "{}", datetime);
+            elapsed.set_rounding(Rounding::RoundNearest);
+            assert_eq!(elapsed.to_string(), *nearest, "{}", datetime);
+        }
+    }
+
+    #[test]
+    fn reference_label_appended() {
+        let context = Local::now();
+        let mut elapsed = Elapsed::new_with_context(context - Duration::days(3), context);
+        elapsed.set_reference_label("deploy");
+        assert_eq!(elapsed.to_string(), "3d ago (relative to deploy)");
+    }
+
+    #[test]
+    fn parse_compact_and_spaced() {
+        let context = Local::now();
+        let diff = Duration::days(4) + Duration::hours(2) + Duration::minutes(5);
+        let mut elapsed = Elapsed::custom_with_context(context - diff, context);
+        elapsed.process_fixed(&[TimeFrame::Day, TimeFrame::Hour, TimeFrame::Minute]);
+        assert_eq!(elapsed.compact(), "4d2hr5min ago");
+        assert_eq!(parse_elapsed(&elapsed.compact()), Ok(-diff));
+        assert_eq!(parse_elapsed(&elapsed.to_string()), Ok(-diff));
+
+        let year = Duration::milliseconds(TimeFrame::Year.millis() as i64);
+        let month = Duration::milliseconds(TimeFrame::Month.millis() as i64);
+        let expected = year * 3 + month * 2 + Duration::days(4);
+        assert_eq!(parse_elapsed("3y2mo4d"), parse_elapsed("3y 2mo 4d"));
+        assert_eq!(parse_elapsed("in 3y2mo4d"), Ok(expected));
+        assert_eq!(
+            parse_elapsed("1min30sec500ms"),
+            Ok(Duration::milliseconds(90_500))
+        );
+        assert!(parse_elapsed("3y2").is_err());
+        assert!(parse_elapsed("y2").is_err());
+    }
+
+    #[test]
+    fn far_sentinels() {
+        let future = Elapsed::far_future();
+        let past = Elapsed::far_past();
+        let normal = Elapsed::new(Local::now() + Duration::weeks(52 * 1_000));
+        assert!(!future.passed);
+        assert!(past.passed);
+        assert!(future.duration > normal.duration);
+        assert!(past.duration < -normal.duration);
+
+        let candidates = [future.clone(), normal.clone(), past];
+        assert_eq!(
+            Elapsed::nearest(&candidates).unwrap().duration,
+            normal.duration
+        );
+        assert!(future.to_string().starts_with("in "));
+    }
+
+    #[test]
+    fn weeks_and_days_only() {
+        let context = Local::now();
+        let elapsed = Elapsed::new_with_context(context - Duration::days(227), context);
+        assert_eq!(elapsed.weeks_and_days(), (32, 3));
+        let elapsed = Elapsed::new_with_context(context + Duration::days(6), context);
+        assert_eq!(elapsed.weeks_and_days(), (0, 6));
+    }
+
+    #[test]
+    fn shared_between_threads() {
+        use std::sync::Arc;
+
+        let shared = Arc::new(Mutex::new(Elapsed::new(Local::now() + Duration::days(3))));
+        let handle = {
+            let shared = Arc::clone(&shared);
+            std::thread::spawn(move || Elapsed::refresh_shared(&shared))
+        };
+        let displayed = handle.join().unwrap();
+        assert!(displayed.starts_with("in 2d"));
+        assert_eq!(displayed, shared.lock().unwrap().to_string());
+    }
+
+    #[test]
+    fn span_label_is_chronological() {
+        let context = local(2019, 3, 1, 0, 0, 0);
+        let target = local(2022, 9, 1, 0, 0, 0);
+        let elapsed = Elapsed::new_with_context(target, context);
+        assert_eq!(elapsed.span_label("%Y"), "2019–2022");
+        let elapsed = Elapsed::new_with_context(context, target);
+        assert_eq!(elapsed.span_label("%Y"), "2019–2022");
+    }
+
+    #[test]
+    fn vague_humanize() {
+        let context = Local::now();
+        let mut few = Elapsed::new_with_context(context - Duration::days(3), context);
+        let mut several = Elapsed::new_with_context(context + Duration::days(7), context);
+        few.set_vague(Some(VagueConfig::default()));
+        several.set_vague(Some(VagueConfig::default()));
+        assert_eq!(few.humanize(), "a few days ago");
+        assert_eq!(several.humanize(), "in several days");
+
+        several.set_vague(Some(VagueConfig {
+            ranges: vec![(2..=7, "a handful of".into())],
+        }));
+        assert_eq!(several.humanize(), "in a handful of days");
+        several.set_vague(None);
+        assert_eq!(several.humanize(), "in 7 days");
+    }
+
+    #[test]
+    fn try_from_rfc3339() {
+        use std::convert::TryInto;
+
+        let elapsed = Elapsed::try_from("2024-01-01T00:00:00Z").unwrap();
+        assert_eq!(elapsed.datetime, Utc.ymd(2024, 1, 1).and_hms(0, 0, 0));
+        let elapsed: Result<Elapsed, _> = "2024-01-01T05:30:00+05:30".try_into();
+        assert_eq!(
+            elapsed.unwrap().datetime,
+            Utc.ymd(2024, 1, 1).and_hms(0, 0, 0)
+        );
+        assert!(Elapsed::try_from("yesterday").is_err());
+    }
+
+    #[test]
+    fn trim_leading_zeros_keeps_inner_zeros() {
+        let context = local(2024, 1, 1, 0, 0, 0);
+        let datetime = local(2024, 3, 4, 0, 0, 0);
+        let mut elapsed = Elapsed::custom_with_context(datetime, context);
+        elapsed.years_and().months_and().weeks_and().days_and();
+        assert_eq!(elapsed.to_string(), "in 0y 2mo 0w 3d");
+        elapsed.set_trim_leading_zeros(true);
+        assert_eq!(elapsed.to_string(), "in 2mo 0w 3d");
+    }
+
+    #[test]
+    fn humanize_short_largest_unit() {
+        let context = Local::now();
+        let diff = Duration::days(3) + Duration::hours(5);
+        let elapsed = Elapsed::new_with_context(context - diff, context);
+        assert_eq!(elapsed.humanize_short(), "3d");
+        let elapsed = Elapsed::new_with_context(context + Duration::minutes(150), context);
+        assert_eq!(elapsed.humanize_short(), "2h");
+        let elapsed = Elapsed::new_with_context(context, context);
+        assert_eq!(elapsed.humanize_short(), "now");
+    }
+
+    #[test]
+    fn under_five_minutes_always_has_seconds() {
+        let context = Local::now();
+        let diffs = [
+            (Duration::seconds(1), "in 0min 1sec"),
+            (Duration::seconds(59), "in 0min 59sec"),
+            (Duration::seconds(4 * 60 + 59), "in 4min 59sec"),
+        ];
+        for (diff, expected) in diffs.iter() {
+            let elapsed = Elapsed::new_with_context(context + *diff, context);
+            assert!(elapsed.get(TimeFrame::Second).is_some(), "{:?}", diff);
+            assert_eq!(elapsed.to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn anchor_context_to_day() {
+        let context = local(2024, 5, 17, 15, 30, 0);
+        let target = local(2024, 5, 18, 12, 0, 0);
+        let mut elapsed = Elapsed::new_with_context(target, context);
+        let before = elapsed.duration;
+        elapsed.anchor_context_to_day_start();
+        assert_eq!(
+            elapsed.duration - before,
+            Duration::hours(15) + Duration::minutes(30)
+        );
+        assert_eq!(
+            elapsed.datetime_context.naive_local().date(),
+            context.naive_local().date()
+        );
+
+        elapsed.anchor_context_to_day_end();
+        assert_eq!(
+            elapsed.duration,
+            Duration::hours(12) + Duration::milliseconds(1)
+        );
+    }
+
+    #[test]
+    fn through_til_reports_frames() {
+        let context = local(2024, 1, 1, 0, 0, 0);
+        let datetime = local(2024, 3, 20, 6, 30, 15);
+        let mut elapsed = Elapsed::custom_with_context(datetime, context);
+        elapsed.seconds_and();
+        let frames = elapsed.through_til_frames(&TimeFrame::Month);
+        assert_eq!(
+            frames,
+            [
+                TimeFrame::Month,
+                TimeFrame::Week,
+                TimeFrame::Day,
+                TimeFrame::Hour,
+                TimeFrame::Minute,
+                TimeFrame::Second,
+            ]
+        );
+        let cached: Vec<TimeFrame> = elapsed.legend().into_iter().map(|(tf, ..)| tf).collect();
+        assert_eq!(frames, cached);
+        assert_eq!(elapsed.to_string(), "in 2mo 2w 5d 6hr 30min 15sec");
+    }
+
+    #[test]
+    fn rate_per_coarsest_unit() {
+        let context = Local::now();
+        let elapsed = Elapsed::new_with_context(context - Duration::days(3), context);
+        let (rate, tf) = elapsed.rate(12);
+        assert!((rate - 4.0).abs() < f64::EPSILON);
+        assert_eq!(tf, TimeFrame::Day);
+
+        let elapsed = Elapsed::new_with_context(context + Duration::minutes(90), context);
+        assert_eq!(elapsed.rate(3), (2.0, TimeFrame::Hour));
+    }
+
+    #[test]
+    fn cache_is_borrowed() {
+        let context = Local::now();
+        let elapsed = Elapsed::new_with_context(context + Duration::days(3), context);
+        let cache = elapsed.cache();
+        assert!(std::ptr::eq(cache, &elapsed.cache));
+        assert_eq!(cache[TimeFrame::Day as usize].as_ref().unwrap().1, 3);
+    }
+
+    #[test]
+    fn leap_seconds_counted() {
+        let context = Utc
+            .ymd(2016, 12, 31)
+            .and_hms(12, 0, 0)
+            .with_timezone(&Local);
+        let mut elapsed = Elapsed::new_with_context(context + Duration::days(1), context);
+        assert_eq!(elapsed.total_seconds_with_leaps(), 86_400);
+        elapsed.set_count_leap_seconds(true);
+        assert_eq!(elapsed.total_seconds_with_leaps(), 86_401);
+
+        let mut elapsed = Elapsed::new_with_context(context - Duration::days(1), context);
+        elapsed.set_count_leap_seconds(true);
+        assert_eq!(elapsed.total_seconds_with_leaps(), -86_400);
+    }
+
+    #[test]
+    fn signed_components_mixed_sign() {
+        let context = local(2020, 3, 10, 12, 0, 0);
+        let datetime = local(2021, 1, 10, 12, 30, 0);
+        let mut elapsed = Elapsed::new_with_context(datetime, context);
+        elapsed.set_signed_components(true);
+        assert_eq!(elapsed.to_string(), "in 1y -2mo 30min");
+
+        elapsed.set_signed_components(false);
+        assert_eq!(elapsed.to_string(), "in 10mo");
+        assert_eq!(elapsed.total_months(), 10);
+        /* The 30 minutes are dropped with months in the breakdown, but can be chained. */
+        elapsed.clear_cache();
+        elapsed.months_and().minutes_and();
+        assert_eq!(elapsed.to_string(), "in 10mo 30min");
+    }
+
+    #[test]
+    fn totals_ignore_cache() {
+        let context = local(2020, 1, 1, 0, 0, 0);
+        let datetime = local(2021, 3, 1, 0, 0, 0);
+        let mut elapsed = Elapsed::custom_with_context(datetime, context);
+        assert_eq!(elapsed.total_months(), 14);
+        elapsed.years_and();
+        assert_eq!(elapsed.months().1, 2);
+        assert_eq!(elapsed.total_months(), 14);
+        assert_eq!(elapsed.weeks().1, 60);
+        assert_eq!(elapsed.total_weeks(), 60);
+        elapsed.months_and().weeks_and();
+        assert_eq!(elapsed.get(TimeFrame::Week).map(|tuple| tuple.1), Some(0));
+        assert_eq!(elapsed.total_weeks(), 60);
+    }
+
+    #[test]
+    fn render_into_fixed_buffer() {
+        /* A fixed capacity buffer, like `heapless::String`. */
+        struct Buffer {
+            bytes: [u8; 16],
+            len: usize,
+        }
+
+        impl core::fmt::Write for Buffer {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let end = self.len + s.len();
+                if end > self.bytes.len() {
+                    return Err(core::fmt::Error);
+                }
+                self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let context = Local::now();
+        let elapsed = Elapsed::new_with_context(context - Duration::days(10), context);
+        let mut buffer = Buffer {
+            bytes: [0; 16],
+            len: 0,
+        };
+        elapsed.render(&mut buffer).unwrap();
+        let rendered = std::str::from_utf8(&buffer.bytes[..buffer.len]).unwrap();
+        assert_eq!(rendered, elapsed.to_string());
+
+        let far = Elapsed::new_with_context(context - Duration::weeks(200), context);
+        assert!(far.render(&mut buffer).is_err());
+    }
+
+    #[test]
+    fn render_matches_display() {
+        let context = local(2024, 6, 1, 12, 0, 0);
+        let mut elapsed = Elapsed::new_with_context(local(2023, 1, 1, 12, 0, 0), context);
+        elapsed
+            .set_over_mode(Some(TimeFrame::Year))
+            .set_reference_label("launch");
+        let mut rendered = String::new();
+        elapsed.render(&mut rendered).unwrap();
+        assert_eq!(rendered, "over 1 year ago (relative to launch)");
+        assert_eq!(rendered, elapsed.to_string());
+
+        let diff = Duration::hours(2) + Duration::minutes(30);
+        let mut elapsed = Elapsed::new_with_context(context + diff, context);
+        elapsed
+            .set_approximate_marker("about ")
+            .set_sign_mode(SignMode::Symbol);
+        elapsed.clear_cache();
+        elapsed.hours_and();
+        let mut rendered = String::new();
+        elapsed.render(&mut rendered).unwrap();
+        assert_eq!(rendered, "+about 2hr");
+        assert_eq!(rendered, elapsed.to_string());
+
+        let mut elapsed = Elapsed::new_with_context(context, context);
+        elapsed.set_zero_text("just now");
+        let mut rendered = String::new();
+        elapsed.render(&mut rendered).unwrap();
+        assert_eq!(rendered, elapsed.to_string());
+    }
+
+    #[test]
+    fn quantize_to_nearest_hour() {
+        let context = Local::now();
+        let diff = Duration::hours(2) + Duration::minutes(47);
+        let mut elapsed = Elapsed::new_with_context(context + diff, context);
+        assert_eq!(elapsed.to_string(), "in 2hr 47min");
+        elapsed.quantize(TimeFrame::Hour);
+        assert_eq!(elapsed.to_string(), "in 3hr");
+
+        let mut elapsed = Elapsed::new_with_context(context - Duration::minutes(89), context);
+        assert_eq!(elapsed.quantize(TimeFrame::Hour).to_string(), "1hr ago");
+    }
+
+    #[test]
+    fn relative_and_absolute_together() {
+        let context = local(2024, 1, 8, 9, 0, 0);
+        let datetime = local(2024, 1, 5, 9, 0, 0);
+        let elapsed = Elapsed::new_with_context(datetime, context);
+        assert_eq!(
+            elapsed.relative_and_absolute("%Y-%m-%d"),
+            "3d ago (2024-01-05)"
+        );
+        assert_eq!(
+            elapsed.relative_and_absolute("%b %-d, %Y"),
+            "3d ago (Jan 5, 2024)"
+        );
+    }
+
+    #[test]
+    fn try_cache_insert_in_range() {
+        let mut elapsed = Elapsed::custom(Local::now());
+        assert_eq!(elapsed.try_cache_insert(TimeFrame::Year, 2), Ok(()));
+        assert_eq!(elapsed.try_cache_insert(TimeFrame::MilliSecond, 5), Ok(()));
+        assert_eq!(elapsed.cached_value(TimeFrame::Year), Some(2));
+        assert_eq!(elapsed.cached_value(TimeFrame::MilliSecond), Some(5));
+        let error = ElapsedError::CacheIndexOutOfBounds(TimeFrame::Year);
+        assert_eq!(
+            error.to_string(),
+            "No slot in the `cache` for `TimeFrame::Year`"
+        );
+    }
+
+    #[test]
+    fn coarsest_with_fraction_for_gauges() {
+        let context = Local::now();
+        let diff = Duration::milliseconds(TimeFrame::Year.millis() as i64 * 3 / 2);
+        let elapsed = Elapsed::new_with_context(context - diff, context);
+        let (tf, whole, fraction) = elapsed.coarsest_with_fraction();
+        assert_eq!((tf, whole), (TimeFrame::Year, 1));
+        assert!((fraction - 0.5).abs() < 1e-9);
+
+        let elapsed = Elapsed::new_with_context(context + Duration::minutes(90), context);
+        assert_eq!(elapsed.coarsest_with_fraction(), (TimeFrame::Hour, 1, 0.5));
+    }
+
+    #[test]
+    fn contextual_calendar_terms() {
+        let context = local(2024, 5, 17, 9, 0, 0);
+        let later = Elapsed::new_with_context(context + Duration::hours(6), context);
+        assert_eq!(later.contextual(), "6hr later today");
+        let yesterday = Elapsed::new_with_context(context - Duration::hours(12), context);
+        assert_eq!(yesterday.contextual(), "yesterday");
+        let weeks = Elapsed::new_with_context(context - Duration::weeks(3), context);
+        assert_eq!(weeks.contextual(), "3w ago");
+    }
+
+    #[test]
+    fn excluded_frames_roll_into_smaller() {
+        let context = Local::now();
+        let mut elapsed = Elapsed::new_with_context(context + Duration::days(17), context);
+        assert_eq!(elapsed.to_string(), "in 2w 3d");
+        elapsed.exclude(&[TimeFrame::Week]);
+        assert_eq!(elapsed.to_string(), "in 17d");
+
+        let context = local(2024, 1, 1, 12, 0, 0);
+        let mut elapsed = Elapsed::new_with_context(context - Duration::weeks(30), context);
+        /*
+        Calendar months from Jun 5th, 27 days are left over; 26 if the clocks went forward an hour
+        in between, so the days are left out.
+        */
+        assert_eq!(elapsed.tokens()[..2], ["6mo", "3w"]);
+        assert!(elapsed.to_string().ends_with("d ago"));
+        elapsed.exclude(&[TimeFrame::Month, TimeFrame::Week]);
+        assert_eq!(elapsed.to_string(), "210d ago");
+        elapsed.exclude(&[TimeFrame::Month]);
+        assert_eq!(elapsed.to_string(), "30w ago");
+    }
+
+    #[test]
+    fn equality_ignores_sub_second_noise() {
+        let context = local(2024, 1, 1, 0, 0, 0);
+        let a = Elapsed::new_with_context(context + Duration::seconds(90), context);
+        let noisy = Duration::seconds(90) + Duration::milliseconds(100);
+        let b = Elapsed::new_with_context(context + noisy, context);
+        assert_eq!(a, b);
+        assert!(!a.exact_eq(&b));
+        assert!(a.exact_eq(&a.clone()));
+        assert_ne!(
+            a,
+            Elapsed::new_with_context(context + Duration::seconds(91), context)
+        );
+    }
+
+    #[test]
+    fn sum_durations() {
+        let durations = vec![
+            Duration::hours(1),
+            Duration::minutes(30),
+            Duration::minutes(15),
+        ];
+        let total: Elapsed = durations.into_iter().sum();
+        assert_eq!(total.duration, Duration::minutes(105));
+        assert_eq!(total.to_string(), "in 1hr 45min");
+
+        let empty: Elapsed = Vec::<Duration>::new().into_iter().sum();
+        assert_eq!(empty.to_string(), "now");
+    }
+
+    #[test]
+    fn next_occurrence() {
+        let context = local(2021, 6, 10, 12, 0, 0);
+        let standup = local(2021, 6, 7, 9, 30, 0);
+        let elapsed = Elapsed::new_with_context(standup, context);
+        let next = elapsed.next_occurrence(Duration::days(1));
+        assert_eq!(next, local(2021, 6, 11, 9, 30, 0));
+        assert!(next > context && next - context < Duration::hours(24));
+
+        /* Exactly on a repetition must still move on to the next. */
+        let elapsed = Elapsed::new_with_context(standup, standup + Duration::days(2));
+        assert_eq!(
+            elapsed.next_occurrence(Duration::days(1)),
+            standup + Duration::days(3)
+        );
+
+        /* Periods below a millisecond. */
+        let since = Duration::milliseconds(1) + Duration::microseconds(200);
+        let elapsed = Elapsed::new_with_context(standup, standup + since);
+        assert_eq!(
+            elapsed.next_occurrence(Duration::microseconds(500)),
+            standup + Duration::microseconds(1500)
+        );
+    }
+
+    #[test]
+    fn almost_next_count() {
+        let context = local(2024, 6, 1, 12, 0, 0);
+        let birth = local(1994, 7, 1, 12, 0, 0);
+        let mut elapsed = Elapsed::new_with_context(birth, context);
+        assert_eq!(elapsed.humanize(), "30 years ago");
+        elapsed.set_almost_threshold(Some(0.9));
+        assert_eq!(elapsed.humanize(), "almost 30 years ago");
+
+        /* Not far enough along. */
+        let birth = local(1994, 10, 1, 12, 0, 0);
+        let mut elapsed = Elapsed::new_with_context(birth, context);
+        elapsed.set_almost_threshold(Some(0.9));
+        assert_eq!(elapsed.humanize(), "30 years ago");
+    }
+
+    #[test]
+    fn extreme_span_checked_accessors() {
+        let (future, past) = (Elapsed::far_future(), Elapsed::far_past());
+        let mut elapsed = Elapsed::new_with_context(future.datetime, past.datetime);
+        elapsed.set_subsec_digits(Some(3)).set_show_millis(true);
+        assert!(elapsed.cache()[TimeFrame::Year as usize].is_some());
+        assert_eq!(elapsed.checked_num_nanoseconds(), None);
+        assert_eq!(elapsed.checked_num_microseconds(), None);
+        assert_eq!(
+            elapsed.checked_num_milliseconds(),
+            Some(elapsed.duration.num_milliseconds())
+        );
+
+        /* Roughly 292 million years, as far as a `Duration` goes. */
+        elapsed.duration = Duration::max_value();
+        elapsed.clear_cache();
+        elapsed.process();
+        assert_eq!(elapsed.checked_num_milliseconds(), Some(i64::MAX));
+        elapsed.duration = Duration::min_value();
+        assert_eq!(elapsed.checked_num_milliseconds(), Some(-i64::MAX));
+
+        let context = Local::now();
+        let elapsed = Elapsed::new_with_context(context + Duration::seconds(2), context);
+        assert_eq!(elapsed.checked_num_milliseconds(), Some(2_000));
+        assert_eq!(elapsed.checked_num_microseconds(), Some(2_000_000));
+        assert_eq!(elapsed.checked_num_nanoseconds(), Some(2_000_000_000));
+    }
+
+    #[test]
+    fn semantics_framing() {
+        let context = Local::now();
+        let (future, past) = (context + Duration::days(3), context - Duration::days(3));
+        let framed = |datetime, semantics| {
+            Elapsed::new_with_semantics(datetime, context, semantics).to_string()
+        };
+        assert_eq!(framed(future, Semantics::DueIn), "due in 3d");
+        assert_eq!(framed(past, Semantics::DueIn), "overdue by 3d");
+        assert_eq!(framed(past, Semantics::ElapsedSince), "3d since");
+        assert_eq!(framed(future, Semantics::ElapsedSince), "3d until");
+        assert_eq!(framed(past, Semantics::Between), "3d between");
+        assert_eq!(framed(future, Semantics::Between), "3d between");
+        assert_eq!(
+            Elapsed::new_with_context(past, context).to_string(),
+            "3d ago"
+        );
+    }
+
+    #[test]
+    fn try_new_surfaces_unknown_offset() {
+        let dt = Local::now() + Duration::days(3);
+        assert_eq!(Elapsed::try_new(dt).unwrap().to_string(), "in 2d");
+        assert!(try_now().is_ok());
+
+        /* A clock whose timezone can't place the local time. */
+        let err = try_now_from(Utc::now(), |_| LocalResult::None).unwrap_err();
+        assert_eq!(err, ElapsedError::UnknownLocalOffset);
+        assert_eq!(err.to_string(), "Unable to determine the `Local` offset");
+    }
+
+    #[test]
+    fn periodicity_words() {
+        let context = Local::now();
+        let about_a_day = context + Duration::hours(24) + Duration::minutes(20);
+        let elapsed = Elapsed::new_with_context(about_a_day, context);
+        assert_eq!(elapsed.periodicity(), Some("daily"));
+        let about_a_week = context - Duration::days(7) + Duration::hours(3);
+        let elapsed = Elapsed::new_with_context(about_a_week, context);
+        assert_eq!(elapsed.periodicity(), Some("weekly"));
+        let elapsed = Elapsed::new_with_context(context + Duration::days(3), context);
+        assert_eq!(elapsed.periodicity(), None);
+    }
+
+    #[test]
+    fn component_durations_sum_back() {
+        let context = Local::now();
+        let diff = Duration::hours(2) + Duration::minutes(30);
+        let elapsed = Elapsed::new_with_context(context - diff, context);
+        assert_eq!(
+            elapsed.component_durations(),
+            vec![
+                (TimeFrame::Hour, Duration::hours(-2)),
+                (TimeFrame::Minute, Duration::minutes(-30))
+            ]
+        );
+
+        let elapsed = Elapsed::new_with_context(context + Duration::weeks(10), context);
+        let sum = elapsed
+            .component_durations()
+            .into_iter()
+            .fold(Duration::zero(), |sum, (_, d)| sum + d);
+        let off = (sum - elapsed.duration).num_milliseconds().abs();
+        assert!(off < elapsed.duration.num_milliseconds() / 10);
+    }
+
+    #[test]
+    fn chain_in_any_order() {
+        let context = local(2021, 1, 1, 0, 0, 0);
+        let dt = context + Duration::weeks(60) + Duration::seconds(42);
+        let mut strict = Elapsed::custom_with_context(dt, context);
+        let expected = strict.years_and().months_and().seconds_and().to_string();
+
+        let mut relaxed = Elapsed::custom_with_context(dt, context);
+        relaxed.allow_any_order();
+        relaxed.seconds_and().years_and().months_and();
+        assert_eq!(relaxed.to_string(), expected);
+    }
+
+    #[test]
+    fn weekday_count_between() {
+        /* Wednesday to Wednesday, 3 weeks apart. */
+        let context = local(2021, 6, 2, 9, 0, 0);
+        let elapsed = Elapsed::new_with_context(context + Duration::weeks(3), context);
+        assert_eq!(elapsed.weekday_count(Weekday::Mon), 3);
+        assert_eq!(elapsed.weekday_count(Weekday::Wed), 2);
+        let elapsed = Elapsed::new_with_context(context - Duration::weeks(3), context);
+        assert_eq!(elapsed.weekday_count(Weekday::Mon), 3);
+
+        /* Monday to Monday, the endpoints don't count. */
+        let monday = local(2021, 6, 7, 9, 0, 0);
+        let elapsed = Elapsed::new_with_context(monday + Duration::weeks(3), monday);
+        assert_eq!(elapsed.weekday_count(Weekday::Mon), 2);
+        let elapsed = Elapsed::new_with_context(monday + Duration::days(1), monday);
+        assert_eq!(elapsed.weekday_count(Weekday::Mon), 0);
+    }
+
+    #[test]
+    fn now_and_elapsed_from_now() {
+        let now = Elapsed::now();
+        assert_eq!(now.direction(), Direction::Now);
+        assert_eq!(now.to_string(), "now");
+
+        let dt = Local::now() + Duration::days(3) + Duration::hours(1);
+        assert_eq!(elapsed_from_now(dt), Elapsed::new(dt).to_string());
+    }
+
+    #[test]
+    fn contextual_same_day_keeps_time_of_day() {
+        let context = local(2024, 5, 17, 15, 0, 0);
+        let morning = local(2024, 5, 17, 13, 0, 0);
+        let elapsed = Elapsed::new_with_context(morning, context);
+        assert_eq!(elapsed.calendar_days(), 0);
+        assert_eq!(elapsed.contextual(), "2hr earlier today");
+        let evening = context + Duration::minutes(90);
+        let elapsed = Elapsed::new_with_context(evening, context);
+        assert_eq!(elapsed.contextual(), "1hr 30min later today");
+    }
+
+    #[test]
+    fn approximate_marker() {
+        let context = local(2024, 5, 17, 9, 0, 0);
+        let dt = context + Duration::minutes(90);
+        let mut elapsed = Elapsed::new_with_context(dt, context);
+        elapsed
+            .set_approximate_marker("~")
+            .set_frame_order(vec![TimeFrame::Hour]);
+        assert_eq!(elapsed.to_string(), "in ~1hr");
+        let mut elapsed = Elapsed::new_with_context(context - Duration::hours(2), context);
+        elapsed.set_approximate_marker("~");
+        assert_eq!(elapsed.to_string(), "2hr ago");
+
+        /* Precision truncating the breakdown is approximate too. */
+        let mut elapsed = Elapsed::new_with_context(dt, context);
+        elapsed.set_approximate_marker("about ");
+        assert_eq!(elapsed.to_string(), "in 1hr 30min");
+        assert_eq!(format!("{:.1}", elapsed), "in about 1hr");
+    }
+
+    #[test]
+    fn diff_of_two_elapsed() {
+        let context = Local::now();
+        let a = Elapsed::new_with_context(context + Duration::days(3), context);
+        let b = Elapsed::new_with_context(context + Duration::days(1), context);
+        let gap = Elapsed::diff_of(&a, &b);
+        assert_eq!(gap.duration, Duration::days(2));
+        assert_eq!(gap.get(TimeFrame::Day).map(|tuple| tuple.1), Some(2));
+        assert_eq!(gap.to_string(), "in 2d");
+        assert_eq!(Elapsed::diff_of(&b, &a).to_string(), "2d ago");
+    }
+
+    #[test]
+    fn week_rounding_ceil() {
+        let context = local(2024, 5, 1, 9, 0, 0);
+        let dt = context + Duration::days(15);
+        let mut elapsed = Elapsed::new_with_context(dt, context);
+        assert_eq!(elapsed.to_string(), "in 2w 1d");
+        assert_eq!(elapsed.weeks().1, 2);
+        elapsed.set_week_rounding(Some(Rounding::Ceil));
+        assert_eq!(elapsed.to_string(), "in 3w");
+        assert_eq!(elapsed.weeks().1, 3);
+    }
+
+    #[test]
+    fn duration_string_std_format() {
+        let context = Local::now();
+        let elapsed = Elapsed::new_with_context(context + Duration::days(3), context);
+        assert_eq!(elapsed.duration_string(), "259200s");
+        let elapsed = Elapsed::new_with_context(context - Duration::milliseconds(1500), context);
+        assert_eq!(elapsed.duration_string(), "-1.5s");
+        assert_eq!(
+            Elapsed::new_with_context(context, context).duration_string(),
+            "0ns"
+        );
+    }
+
+    #[test]
+    fn from_naive_dates() {
+        let context = NaiveDate::from_ymd(2021, 3, 1);
+        let mut elapsed = Elapsed::from_dates(NaiveDate::from_ymd(2022, 3, 1), context);
+        assert_eq!(elapsed.years().0, "1y");
+        assert_eq!(elapsed.to_string(), "in 1y");
+        assert_eq!(elapsed.total_months(), 12);
+        assert!(!elapsed.passed);
+        let mut elapsed = Elapsed::from_dates(NaiveDate::from_ymd(2020, 3, 1), context);
+        assert_eq!(elapsed.years().0, "1y");
+        assert_eq!(
+            elapsed.datetime.time(),
+            chrono::NaiveTime::from_hms(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn override_process_thresholds() {
+        let context = Local::now();
+        let dt = context + Duration::hours(5) + Duration::minutes(20);
+        let mut elapsed = Elapsed::new_with_context(dt, context);
+        assert_eq!(elapsed.thresholds(), ProcessThresholds::default());
+        assert_eq!(elapsed.to_string(), "in 5hr");
+        elapsed.set_thresholds(ProcessThresholds {
+            hours_alone: 8,
+            ..ProcessThresholds::default()
+        });
+        assert_eq!(elapsed.to_string(), "in 5hr 20min");
+    }
+
+    #[test]
+    fn display_time_frame() {
+        assert_eq!(TimeFrame::Year.to_string(), "year");
+        assert_eq!(format!("{:>6}", TimeFrame::Hour), "  hour");
+    }
+
+    #[test]
+    fn numeric_export() {
+        let context = Local::now();
+        let elapsed = Elapsed::new_with_context(context - Duration::minutes(90), context);
+        assert_eq!(elapsed.numeric_in(TimeFrame::Minute), 90);
+        assert_eq!(elapsed.numeric_string_in(TimeFrame::Minute), "90");
+        assert_eq!(elapsed.numeric_in(TimeFrame::Hour), 1);
+        assert_eq!(elapsed.numeric_string_in(TimeFrame::Second), "5400");
+    }
+
+    #[test]
+    fn sub_second_differences() {
+        let context = Local::now();
+        let mut elapsed = Elapsed::new_with_context(context + Duration::milliseconds(2), context);
+        assert!(elapsed.cache().iter().all(Option::is_none));
+        assert_eq!(elapsed.to_string(), "now");
+        assert_eq!(elapsed.direction(), Direction::Future);
+        assert_eq!(elapsed.compact(), "now");
+        assert_eq!(elapsed.contextual(), "now");
+        assert_eq!(elapsed.describe(), "This event is due now.");
+        let mut buffer = String::new();
+        elapsed.render(&mut buffer).unwrap();
+        assert_eq!(buffer, "now");
+        elapsed.set_zero_text("instant");
+        assert_eq!(elapsed.to_string(), "instant");
+
+        let elapsed = Elapsed::new_with_context(context - Duration::milliseconds(250), context);
+        assert_eq!(elapsed.to_string(), "250ms ago");
+        let elapsed = Elapsed::new_with_context(context + Duration::milliseconds(250), context);
+        assert_eq!(elapsed.tokens(), vec!["250ms"]);
     }
 }